@@ -1,62 +1,119 @@
 extern crate core;
 
-use commun::decodage::{decode_b64, decode_message, decode_radar_view_binary};
+mod manual;
+mod summary;
+mod tui;
+
+use commun::decodage::{decode_b64, decode_message, decode_radar_view_binary, ProtocolError};
 use commun::encodage::encode_message;
 use commun::structs::{
     Action, ActionError, Challenge, Hint, JsonWrapper, RegisterTeam, RegisterTeamResult,
     SubscribePlayer, SubscribePlayerResult,
 };
 use maze_engine::challenge::ChallengeManager;
-use maze_engine::navigation::Navigator;
-use std::io::{Result, Write};
+use maze_engine::export::{self, render_text_window};
+use maze_engine::log;
+use maze_engine::navigation::{ExplorationStrategy, Navigator, DEFAULT_COMPASS_WEIGHT};
+use std::collections::BTreeMap;
+use std::io::{ErrorKind, Result, Write};
 use std::net::TcpStream;
+use std::sync::{Arc, Mutex, MutexGuard};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use maze_engine::scoring::ScoreManager;
+use maze_engine::team_map::{TeamMap, TeamMember};
+use manual::Command;
+use summary::{GameSummary, Outcome, PlayerSummary, EXIT_USAGE};
+use tui::{PlayerPanel, Tui};
+
+/// Nombre maximal de réponses envoyées pour un même défi avant de reprendre l'exploration.
+const MAX_CHALLENGE_ATTEMPTS: u32 = 3;
+/// Attente avant de rejouer un déplacement bloqué par un adversaire.
+const RETRY_DELAY: Duration = Duration::from_millis(100);
+/// Intervalle entre deux vérifications de l'arrivée des secours.
+const RESCUE_DELAY: Duration = Duration::from_millis(500);
+/// Durée maximale d'attente des secours avant de retenter un déplacement.
+const RESCUE_TIMEOUT: Duration = Duration::from_secs(60);
 
 struct Client {
     stream: TcpStream,
     challenge_manager: ChallengeManager,
     navigator: Navigator,
     last_challenge: Option<Challenge>,
-    score_manager: ScoreManager,
-    player_id: u32,
-    compass_hint: Option<f32>,
+    /// Score de l'équipe, partagé par les joueurs.
+    score_manager: Arc<Mutex<ScoreManager>>,
+    /// Nom du joueur, connu après l'inscription.
+    name: String,
+    /// Interface plein écran, si elle est activée.
+    tui: Option<Tui>,
+    /// Déplacements et réponses aux défis saisis au clavier plutôt que choisis par le `Navigator`.
+    manual: bool,
+    /// Début de la partie du joueur.
+    started: Instant,
+    /// Une réponse à un défi a été envoyée et n'a pas encore été refusée.
+    challenge_pending: bool,
+    /// Nombre d'`ActionError` reçues, par type.
+    errors: BTreeMap<String, u32>,
+    /// Dernière vue radar reçue, rejouée lorsqu'une action est refusée.
+    last_radar: Option<[[char; 7]; 7]>,
+    /// Nombre de réponses envoyées pour le défi en cours.
+    challenge_attempts: u32,
 }
 
 impl Client {
-    fn new(server: &str, team_size: u32) -> Result<Self> {
+    fn new(server: &str, team_size: u32, navigator: Navigator) -> Result<Self> {
         let stream = TcpStream::connect(server)?;
-        println!("Connected to server at {}", server);
+        log!("Connected to server at {}", server);
         Ok(Client {
             stream,
             challenge_manager: ChallengeManager {
                 secrets: Default::default(),
                 sos_active: None,
             },
-            navigator: Navigator::new(),
+            navigator,
             last_challenge: None,
-            score_manager: ScoreManager::new(team_size),
-            player_id: 0,
-            compass_hint: None,
+            score_manager: Arc::new(Mutex::new(ScoreManager::new(team_size))),
+            name: String::new(),
+            tui: None,
+            manual: false,
+            started: Instant::now(),
+            challenge_pending: false,
+            errors: BTreeMap::new(),
+            last_radar: None,
+            challenge_attempts: 0,
         })
     }
 
+    /// Écrit la carte explorée et le chemin parcouru dans `<préfixe>.svg` et `<préfixe>.txt`.
+    fn export_map(&self, prefix: &str) -> Result<()> {
+        let path = self.navigator.path();
+        export::write_svg(&self.navigator.global_map, &path, format!("{}.svg", prefix))?;
+        export::write_text(&self.navigator.global_map, &path, format!("{}.txt", prefix))?;
+        log!("Map exported to {}.svg and {}.txt", prefix, prefix);
+        Ok(())
+    }
+
     fn send_message(&mut self, message: &JsonWrapper) -> Result<()> {
         let encoded_message = encode_message(message)
-            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, format!("{:?}", e)))?;
+            .map_err(|e| std::io::Error::other(format!("{:?}", e)))?;
         self.stream.write_all(&encoded_message)?;
-        println!("Sent message: {:?}", message);
+        log!("Sent message: {:?}", message);
         Ok(())
     }
 
     fn receive_message(&mut self) -> Result<JsonWrapper> {
-        let message = decode_message(&mut self.stream)
-            .map_err(|e| std::io::Error::new(std::io::ErrorKind::Other, format!("{:?}", e)))?;
-        println!("Received message: {:?}", message);
+        // Les erreurs d'entrée/sortie sont conservées telles quelles pour détecter la fin de la connexion.
+        let message = decode_message(&mut self.stream).map_err(|e| match e {
+            ProtocolError::IoError(e) => e,
+            ProtocolError::SerdeError(e) => std::io::Error::new(ErrorKind::InvalidData, e),
+        })?;
+        log!("Received message: {:?}", message);
         Ok(message)
     }
 
-    fn register_team(&mut self, team_name: &str) -> Result<String> {
+    /// Inscrit l'équipe et retourne le jeton d'inscription ainsi que le nombre de joueurs attendus.
+    fn register_team(&mut self, team_name: &str) -> Result<(String, u8)> {
         let registration = JsonWrapper::RegisterTeam(RegisterTeam {
             name: team_name.to_string(),
         });
@@ -68,20 +125,17 @@ impl Client {
                                                 registration_token,
                                                 expected_players,
                                             }) => {
-                println!(
+                log!(
                     "Team registered successfully. Expected players: {}",
                     expected_players
                 );
-                Ok(registration_token)
+                Ok((registration_token, expected_players))
             }
             JsonWrapper::RegisterTeamResult(RegisterTeamResult::Err(err)) => {
-                println!("Registration error: {:?}", err);
-                Err(std::io::Error::new(std::io::ErrorKind::Other, "Registration error"))
+                log!("Registration error: {:?}", err);
+                Err(std::io::Error::other("Registration error"))
             }
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                "Unexpected response",
-            )),
+            _ => Err(std::io::Error::other("Unexpected response")),
         }
     }
 
@@ -95,21 +149,215 @@ impl Client {
 
         match self.receive_message()? {
             JsonWrapper::SubscribePlayerResult(SubscribePlayerResult::Ok) => {
-                println!("Player subscribed successfully");
+                log!("Player subscribed successfully");
+                self.name = player_name.to_string();
                 Ok(())
             }
             JsonWrapper::SubscribePlayerResult(SubscribePlayerResult::Err(err)) => {
-                println!("Subscription error: {:?}", err);
-                Err(std::io::Error::new(std::io::ErrorKind::Other, "Subscription error"))
+                log!("Subscription error: {:?}", err);
+                Err(std::io::Error::other("Subscription error"))
+            }
+            _ => Err(std::io::Error::other("Unexpected response")),
+        }
+    }
+
+    /// Met à jour le panneau du joueur dans l'interface plein écran, si elle est activée.
+    fn refresh_tui(&self, update: impl FnOnce(&mut PlayerPanel)) {
+        if let Some(tui) = &self.tui {
+            tui.update(&self.name, update);
+        }
+    }
+
+    /// Affiche la vue radar et la carte, dans l'interface plein écran si elle est activée.
+    fn show_radar(&self, radar_view: &[[char; 7]; 7]) {
+        if self.tui.is_none() {
+            self.navigator.display_memory_map();
+            return;
+        }
+        let (columns, rows) = Tui::map_window();
+        let position = self.navigator.current_position;
+        let teammates: Vec<((i32, i32), char)> = self
+            .navigator
+            .team()
+            .map(TeamMember::teammate_positions)
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(id, cell)| (cell, char::from_digit(id, 10).unwrap_or('T')))
+            .collect();
+        let map = render_text_window(&self.navigator.global_map, &self.navigator.path(), &teammates, position, columns, rows);
+        let score = self.scores().team_score();
+        let radar_view = *radar_view;
+        self.refresh_tui(|panel| {
+            panel.radar = Some(radar_view);
+            panel.position = position;
+            panel.score = score;
+            panel.map = map;
+        });
+    }
+
+    /// Retourne la réponse à envoyer pour un défi : la réponse calculée, ou celle saisie au clavier
+    /// en mode manuel (`None` si le joueur abandonne).
+    fn challenge_answer(&self, suggestion: String) -> Result<Option<String>> {
+        if !self.manual {
+            return Ok(Some(suggestion));
+        }
+        manual::read_answer(&suggestion, |typed| {
+            self.refresh_tui(|panel| panel.status = format!("Réponse (Entrée : {}) : {}", suggestion, typed))
+        })
+    }
+
+    /// Retourne le bilan de la partie du joueur.
+    fn summary(&self, outcome: Outcome, error: Option<String>) -> PlayerSummary {
+        let mut scores = self.scores();
+        scores.set_elapsed(self.player_id(), self.started.elapsed());
+        PlayerSummary {
+            player: self.name.clone(),
+            outcome,
+            error,
+            errors: self.errors.clone(),
+            score: scores.player_score(self.player_id()),
+        }
+    }
+
+    /// Verrouille le score de l'équipe.
+    fn scores(&self) -> MutexGuard<'_, ScoreManager> {
+        // Un joueur ayant paniqué ne doit pas priver ses coéquipiers du score.
+        self.score_manager.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    /// Choisit le prochain déplacement depuis une vue radar (au clavier en mode manuel) et l'envoie au serveur.
+    ///
+    /// # Retourne
+    ///
+    /// `Some(Outcome::Quit)` si le joueur humain quitte la partie.
+    fn play_turn(&mut self, radar_view: [[char; 7]; 7]) -> Result<Option<Outcome>> {
+        let best_move = if self.manual {
+            self.navigator.observe(&radar_view);
+            self.show_radar(&radar_view);
+            self.refresh_tui(|panel| panel.status = manual::HELP.to_string());
+            match manual::read_command()? {
+                Command::Move(direction) => {
+                    self.navigator.record_move(direction);
+                    direction
+                }
+                Command::Quit => {
+                    log!("[Client] Player left the game");
+                    return Ok(Some(Outcome::Quit));
+                }
+            }
+        } else {
+            let rescues_performed = self.navigator.rescues_performed();
+            let direction = self.navigator.choose_next_move(&radar_view);
+            if self.navigator.rescues_performed() > rescues_performed {
+                self.scores().add_rescue(self.player_id());
+            }
+            self.show_radar(&radar_view);
+            direction
+        };
+        log!("[Client] Moving in direction: {:?}", best_move);
+        self.scores().add_move(self.player_id());
+        self.refresh_tui(|panel| panel.moves += 1);
+
+        // Envoi de l'action au serveur
+        if let Err(e) = self.send_message(&JsonWrapper::Action(Action::MoveTo(best_move))) {
+            log!("Erreur d'envoi de message: {}", e);
+            return Err(e);
+        }
+        Ok(None)
+    }
+
+    /// Rejoue un tour depuis la dernière vue radar, le serveur n'en envoyant pas de nouvelle après une erreur.
+    fn replay_turn(&mut self) -> Result<Option<Outcome>> {
+        match self.last_radar {
+            Some(radar_view) => self.play_turn(radar_view),
+            None => {
+                log!("[Client] No radar view received yet, nothing to replay");
+                Ok(None)
+            }
+        }
+    }
+
+    /// Identifiant du joueur dans son équipe (`0` s'il joue seul).
+    fn player_id(&self) -> u32 {
+        self.navigator.team().map_or(0, TeamMember::id)
+    }
+
+    /// Recopie dans le `ChallengeManager` le dernier secret de chaque coéquipier.
+    ///
+    /// # Retourne
+    ///
+    /// Les identifiants de tous les joueurs dont un secret est connu.
+    fn collect_team_secrets(&mut self) -> Vec<u32> {
+        if let Some(team) = self.navigator.team() {
+            for (player_id, secret) in team.secrets() {
+                self.challenge_manager.set_secret(player_id, secret);
+            }
+        }
+        let mut player_ids: Vec<u32> = self.challenge_manager.secrets.keys().copied().collect();
+        player_ids.sort_unstable();
+        player_ids
+    }
+
+    /// Répond au dernier défi reçu ; sans défi à résoudre, reprend l'exploration.
+    fn answer_challenge(&mut self) -> Result<Option<Outcome>> {
+        match self.last_challenge {
+            Some(Challenge::SecretSumModulo(modulo)) => {
+                self.challenge_attempts += 1;
+                let player_ids = self.collect_team_secrets();
+                let suggestion = self.challenge_manager.solve_secret_sum_modulo(modulo, &player_ids);
+                let Some(answer) = self.challenge_answer(suggestion.to_string())? else {
+                    return Ok(Some(Outcome::Quit));
+                };
+                log!("[Client] Solving SecretModulo with answer: {}", answer);
+                self.send_message(&JsonWrapper::Action(Action::SolveChallenge { answer }))?;
+                self.challenge_pending = true;
+                self.scores().add_challenge_attempt(self.player_id());
+                Ok(None)
+            }
+            Some(Challenge::SOS) => {
+                log!("Received SOS challenge, calling teammates for help...");
+                self.last_challenge = None;
+                self.wait_for_rescue();
+                self.replay_turn()
+            }
+            None => self.replay_turn(),
+        }
+    }
+
+    /// Diffuse la position du joueur à ses coéquipiers puis attend que l'un d'eux l'ait rejoint.
+    ///
+    /// L'attente est bornée par `RESCUE_TIMEOUT` ; le joueur retente ensuite de se déplacer.
+    fn wait_for_rescue(&self) {
+        if !self.navigator.awaiting_rescue() {
+            if !self.navigator.request_rescue() {
+                log!("[Client] Teammates cannot locate the player yet");
+                thread::sleep(RESCUE_DELAY);
+                return;
+            }
+            self.scores().add_rescue_request(self.player_id());
+        }
+        log!("[Client] Waiting for a teammate to rescue the player");
+        self.refresh_tui(|panel| panel.status = "SOS : en attente de secours".to_string());
+        let started = Instant::now();
+        while self.navigator.awaiting_rescue() {
+            if started.elapsed() >= RESCUE_TIMEOUT {
+                log!("[Client] No teammate came to the rescue after {:?}", RESCUE_TIMEOUT);
+                return;
             }
-            _ => Err(std::io::Error::new(
-                std::io::ErrorKind::Other,
-                "Unexpected response",
-            )),
+            thread::sleep(RESCUE_DELAY);
         }
+        log!("[Client] Player rescued");
+        self.refresh_tui(|panel| panel.status = "SOS : secouru".to_string());
     }
 
-    fn game_loop(&mut self) -> Result<()> {
+    /// Joue jusqu'à la fin de la partie.
+    ///
+    /// # Retourne
+    ///
+    /// `Outcome::Finished` lorsque le serveur ferme proprement la connexion (labyrinthe fini),
+    /// `Outcome::Quit` si le joueur humain quitte la partie, ou l'erreur ayant interrompu le joueur.
+    fn game_loop(&mut self) -> Result<Outcome> {
+        self.started = Instant::now();
         loop {
             // Rajouter un thread:sleep pour ralentir l'exécution en cas de besoin
             // let sleeping_time = time::Duration::from_millis(100);
@@ -119,25 +367,31 @@ impl Client {
             let message = match self.receive_message() {
                 Ok(msg) => msg,
                 Err(e) => {
-                    // Si la connexion est fermée (labyrinthe fini), afficher le score final
-                    if e.kind() == std::io::ErrorKind::ConnectionAborted || e.kind() == std::io::ErrorKind::UnexpectedEof {
-                        let final_score = self.score_manager.compute_score();
-                        println!("Labyrinthe fini! Score final: {}", final_score);
-                        continue;
-                    } else {
-                        println!("Error receiving message: {:?}", e);
-                        continue;
+                    // Si la connexion est fermée proprement (labyrinthe fini), afficher le score final
+                    if let Some(outcome) = Outcome::from_disconnection(e.kind(), self.last_radar.is_some()) {
+                        let final_score = self.scores().team_score();
+                        log!("Labyrinthe fini! Score final: {}", final_score);
+                        self.refresh_tui(|panel| panel.status = "Labyrinthe fini".to_string());
+                        return Ok(outcome);
                     }
+                    log!("Error receiving message: {:?}", e);
+                    return Err(e);
                 }
             };
 
             match message {
                 JsonWrapper::RadarView(encoded_radar) => {
+                    // Une nouvelle vue après une réponse signifie que le défi est résolu.
+                    if self.challenge_pending {
+                        self.challenge_pending = false;
+                        self.scores().add_challenge_solved(self.player_id());
+                        self.last_challenge = None;
+                    }
                     // Décodage Base64 via la fonction interne
                     let radar_bytes = match decode_b64(&encoded_radar) {
                         Ok(bytes) => bytes,
                         Err(e) => {
-                            println!("Erreur de décodage Base64 du RadarView: {}", e);
+                            log!("Erreur de décodage Base64 du RadarView: {}", e);
                             return Err(std::io::Error::new(std::io::ErrorKind::InvalidData, e));
                         }
                     };
@@ -145,89 +399,109 @@ impl Client {
                     // Décodage en structure RadarView
                     let radar_view_array = decode_radar_view_binary(radar_bytes);
 
-                    // Sélection du prochain déplacement
-                    let best_move = self.navigator.choose_next_move(&radar_view_array);
-                    println!("[Client] Moving in direction: {:?}", best_move);
-                    self.navigator.display_memory_map();
-
-                    // Envoi de l'action au serveur
-                    if let Err(e) = self.send_message(&JsonWrapper::Action(Action::MoveTo(best_move))) {
-                        println!("Erreur d'envoi de message: {}", e);
-                        return Err(e);
+                    self.last_radar = Some(radar_view_array);
+                    if let Some(outcome) = self.play_turn(radar_view_array)? {
+                        return Ok(outcome);
                     }
                 }
 
-
                 JsonWrapper::Hint(hint) => {
-                    println!("Received hint: {:?}", hint);
+                    log!("Received hint: {:?}", hint);
+                    self.navigator.record_hint();
+                    self.refresh_tui(|panel| panel.push_hint(format!("{:?}", hint)));
                     match hint {
                         Hint::RelativeCompass { angle } => {
-                            println!("Stored compass hint: {}°", angle);
-                            // Traitement de l'indice boussole
-                            // self.compass_hint = Some(angle);
+                            log!("Stored compass hint: {}°", angle);
+                            self.navigator.set_compass_hint(angle);
                         },
+                        Hint::GridSize { columns, rows } => {
+                            self.navigator.set_grid_size(columns, rows);
+                        }
                         Hint::Secret(secret) => {
-                            println!("Received secret: {}", secret);
-                            self.challenge_manager.set_secret(0, secret);
+                            log!("Received secret: {}", secret);
+                            let player_id = self.player_id();
+                            self.challenge_manager.set_secret(player_id, secret);
+                            if let Some(team) = self.navigator.team() {
+                                team.share_secret(secret);
+                            }
                         }
                         _ => {}
                     }
                 }
                 JsonWrapper::Challenge(challenge) => {
-                    println!("Received challenge: {:?}", challenge);
+                    log!("Received challenge: {:?}", challenge);
                     self.last_challenge = Some(challenge.clone());
+                    self.refresh_tui(|panel| panel.challenge = Some(format!("{:?}", challenge)));
 
-                    match challenge {
-                        Challenge::SecretSumModulo(modulo) => {
-                            let answer =
-                                self.challenge_manager.solve_secret_sum_modulo(modulo, &[0]);
-                            println!("[Client] Solving SecretModulo with answer: {}", answer);
-                            self.send_message(&JsonWrapper::Action(Action::SolveChallenge {
-                                answer: answer.to_string(),
-                            }))?;
-                        }
-                        Challenge::SOS => {
-                            println!("Received SOS challenge, attempting resolution...");
-                            match self.challenge_manager.resolve_sos(0) {
-                                Ok(_) => println!("SOS resolved successfully!"),
-                                Err(err) => println!("Failed to resolve SOS: {:?}", err),
-                            }
-                        }
+                    self.challenge_attempts = 0;
+                    if let Some(outcome) = self.answer_challenge()? {
+                        return Ok(outcome);
                     }
                 }
                 JsonWrapper::ActionError(error) => {
-                    println!("Received action error: {:?}", error);
-                    match error {
+                    log!("Received action error: {:?}", error);
+                    self.refresh_tui(|panel| panel.status = format!("{:?}", error));
+                    *self.errors.entry(format!("{:?}", error)).or_default() += 1;
+                    if matches!(
+                        error,
+                        ActionError::CannotPassThroughWall
+                            | ActionError::CannotPassThroughOpponent
+                            | ActionError::PlayerMustBeRescued
+                            | ActionError::SolveChallengeFirst
+                    ) {
+                        self.scores().add_failed_move(self.player_id());
+                    }
+                    // Le serveur ne renvoie rien d'autre qu'une erreur : une nouvelle action doit toujours être envoyée.
+                    let outcome = match error {
                         ActionError::CannotPassThroughWall => {
                             if let Some(last_dir) = self.navigator.movement_history.back().cloned() {
                                 self.navigator.handle_move_failure(last_dir);
                             } else {
-                                println!("No recorded move to revert.");
+                                log!("No recorded move to revert.");
                             }
-                        },
+                            self.replay_turn()?
+                        }
+                        ActionError::CannotPassThroughOpponent => {
+                            log!("[Client] Cell occupied by an opponent, choosing again");
+                            self.navigator.cancel_move();
+                            thread::sleep(RETRY_DELAY);
+                            self.replay_turn()?
+                        }
+                        ActionError::PlayerMustBeRescued => {
+                            self.navigator.cancel_move();
+                            self.wait_for_rescue();
+                            self.replay_turn()?
+                        }
                         ActionError::SolveChallengeFirst => {
-                            println!("A challenge must be solved first!");
-                        },
+                            log!("A challenge must be solved first!");
+                            self.navigator.cancel_move();
+                            if self.last_challenge.is_some() {
+                                self.answer_challenge()?
+                            } else {
+                                self.replay_turn()?
+                            }
+                        }
                         ActionError::InvalidChallengeSolution => {
-                            println!("Invalid solution, retrying challenge...");
-                            if let Some(last_challenge) = &self.last_challenge {
-                                match last_challenge {
-                                    Challenge::SecretSumModulo(modulo) => {
-                                        let answer = self.challenge_manager.solve_secret_sum_modulo(*modulo, &[0]);
-                                        println!("Retrying SecretModulo with new answer: {}", answer);
-                                        self.send_message(&JsonWrapper::Action(Action::SolveChallenge {
-                                            answer: answer.to_string(),
-                                        }))?;
-                                    }
-                                    _ => {
-                                        println!("No retry strategy for this challenge.");
-                                    }
-                                }
+                            self.challenge_pending = false;
+                            if self.challenge_attempts < MAX_CHALLENGE_ATTEMPTS {
+                                log!("Invalid solution, retrying challenge...");
+                                self.answer_challenge()?
                             } else {
-                                println!("No challenge stored, cannot retry.");
+                                log!("[Client] Giving up the challenge after {} attempts", self.challenge_attempts);
+                                self.scores().add_challenge_abandoned(self.player_id());
+                                self.last_challenge = None;
+                                self.replay_turn()?
                             }
-                        },
-                        _ => {}
+                        }
+                        ActionError::NoRunningChallenge => {
+                            log!("[Client] No running challenge, resuming exploration");
+                            self.challenge_pending = false;
+                            self.last_challenge = None;
+                            self.replay_turn()?
+                        }
+                    };
+                    if let Some(outcome) = outcome {
+                        return Ok(outcome);
                     }
                 }
                 _ => {}
@@ -236,18 +510,147 @@ impl Client {
     }
 }
 
+/// Options de la ligne de commande :
+/// `client [adresse] [--strategy <nom>] [--compass-weight <poids>] [--export <préfixe>] [--tui] [--manual] [--summary <fichier>]`.
+struct Options {
+    host: String,
+    strategy: ExplorationStrategy,
+    compass_weight: f32,
+    /// Préfixe des fichiers de carte (`<préfixe>-<joueur>.svg` et `.txt`) écrits en fin de partie.
+    export: Option<String>,
+    /// Affiche l'interface plein écran au lieu du détail de chaque déplacement.
+    tui: bool,
+    /// Le premier joueur est dirigé au clavier (implique `--tui`).
+    manual: bool,
+    /// Fichier JSON où écrire le bilan de la partie.
+    summary: Option<String>,
+}
+
+fn parse_args(args: impl Iterator<Item = String>) -> std::result::Result<Options, String> {
+    let mut options = Options {
+        host: "localhost".to_string(),
+        strategy: ExplorationStrategy::Frontier,
+        compass_weight: DEFAULT_COMPASS_WEIGHT,
+        export: None,
+        tui: false,
+        manual: false,
+        summary: None,
+    };
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--strategy" => {
+                let name = args.next().ok_or("--strategy attend un nom de stratégie")?;
+                options.strategy = name.parse()?;
+            }
+            "--compass-weight" => {
+                let weight = args.next().ok_or("--compass-weight attend une valeur")?;
+                options.compass_weight = weight
+                    .parse()
+                    .map_err(|_| format!("Poids de boussole invalide : {}", weight))?;
+            }
+            "--tui" => options.tui = true,
+            "--manual" => {
+                options.manual = true;
+                options.tui = true;
+            }
+            "--export" => {
+                options.export = Some(args.next().ok_or("--export attend un préfixe de fichier")?);
+            }
+            "--summary" => {
+                options.summary = Some(args.next().ok_or("--summary attend un nom de fichier")?);
+            }
+            _ if arg.starts_with("--") => return Err(format!("Option inconnue : {}", arg)),
+            _ => options.host = arg,
+        }
+    }
+    Ok(options)
+}
+
 fn main() -> Result<()> {
     const SERVER_PORT: u16 = 8778;
-    let server_addr = format!("localhost:{}", SERVER_PORT);
+    const TEAM_NAME: &str = "rust_warriors";
+    let options = match parse_args(std::env::args().skip(1)) {
+        Ok(options) => options,
+        Err(e) => {
+            eprintln!("{}", e);
+            std::process::exit(EXIT_USAGE);
+        }
+    };
+    let server_addr = format!("{}:{}", options.host, SERVER_PORT);
+    log!("Using navigation strategy: {}", options.strategy);
+    let started = Instant::now();
+
+    let mut client = Client::new(&server_addr, 3, Navigator::new())?;
 
-    let mut client = Client::new(&server_addr, 3)?;
+    let (token, expected_players) = client.register_team(TEAM_NAME)?;
+    log!("Got registration token: {}", token);
 
-    let token = client.register_team("rust_warriors")?;
-    println!("Got registration token: {}", token);
+    let tui = if options.tui { Some(Tui::start()?) } else { None };
 
-    let mut new_client = Client::new(&server_addr, 3)?;
-    new_client.subscribe_player("player1", &token)?;
-    new_client.game_loop()?;
+    // Chaque joueur joue dans son propre thread ; la carte de l'équipe est partagée entre eux.
+    let team_map = TeamMap::new();
+    let score_manager = Arc::new(Mutex::new(ScoreManager::new(expected_players as u32)));
+    let players: Vec<_> = (1..=expected_players)
+        .map(|index| {
+            let player_name = format!("player{}", index);
+            let mut navigator = Navigator::with_strategy(options.strategy);
+            navigator.set_compass_weight(options.compass_weight);
+            navigator.join_team(team_map.join(&player_name));
+            let (server_addr, token, export) = (server_addr.clone(), token.clone(), options.export.clone());
+            let tui = tui.clone();
+            let score_manager = score_manager.clone();
+            let manual = options.manual;
+            let name = player_name.clone();
+            let handle = thread::spawn(move || -> PlayerSummary {
+                let mut player_client = match Client::new(&server_addr, expected_players as u32, navigator) {
+                    Ok(player_client) => player_client,
+                    Err(e) => return PlayerSummary::failed(&player_name, e),
+                };
+                player_client.tui = tui;
+                player_client.score_manager = score_manager;
+                player_client.manual = manual && index == 1;
+                if let Err(e) = player_client.subscribe_player(&player_name, &token) {
+                    return PlayerSummary::failed(&player_name, e);
+                }
+                let summary = match player_client.game_loop() {
+                    Ok(outcome) => player_client.summary(outcome, None),
+                    Err(e) => player_client.summary(Outcome::Failed, Some(e.to_string())),
+                };
+                if let Some(prefix) = export {
+                    if let Err(e) = player_client.export_map(&format!("{}-{}", prefix, player_name)) {
+                        eprintln!("Failed to export map of {}: {}", player_name, e);
+                    }
+                }
+                summary
+            });
+            (name, handle)
+        })
+        .collect();
 
-    Ok(())
+    let players = players
+        .into_iter()
+        .map(|(name, handle)| {
+            handle
+                .join()
+                .unwrap_or_else(|_| PlayerSummary::failed(&name, "player thread panicked"))
+        })
+        .collect();
+
+    if let Some(tui) = tui {
+        tui.stop()?;
+    }
+
+    let summary = GameSummary {
+        team: TEAM_NAME.to_string(),
+        duration_secs: started.elapsed().as_secs_f64(),
+        players,
+        score: score_manager.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).report(),
+    };
+    summary.print();
+    if let Some(file) = &options.summary {
+        summary.write_json(file)?;
+        log!("Summary written to {}", file);
+    }
+    std::process::exit(summary.exit_code());
 }
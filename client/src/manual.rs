@@ -0,0 +1,99 @@
+use commun::structs::RelativeDirection;
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyEventKind, KeyModifiers};
+use crossterm::terminal;
+use std::io;
+
+/// Aide affichée au joueur humain.
+pub const HELP: &str = "Flèches/WASD : se déplacer, q : quitter";
+
+/// Commande saisie au clavier par le joueur humain.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Command {
+    Move(RelativeDirection),
+    Quit,
+}
+
+/// Traduit une touche en commande de déplacement (flèches ou WASD).
+fn command_for(key: &KeyEvent) -> Option<Command> {
+    if key.modifiers.contains(KeyModifiers::CONTROL) && key.code == KeyCode::Char('c') {
+        return Some(Command::Quit);
+    }
+    match key.code {
+        KeyCode::Up | KeyCode::Char('w') | KeyCode::Char('W') => Some(Command::Move(RelativeDirection::Front)),
+        KeyCode::Right | KeyCode::Char('d') | KeyCode::Char('D') => Some(Command::Move(RelativeDirection::Right)),
+        KeyCode::Down | KeyCode::Char('s') | KeyCode::Char('S') => Some(Command::Move(RelativeDirection::Back)),
+        KeyCode::Left | KeyCode::Char('a') | KeyCode::Char('A') => Some(Command::Move(RelativeDirection::Left)),
+        KeyCode::Char('q') | KeyCode::Char('Q') | KeyCode::Esc => Some(Command::Quit),
+        _ => None,
+    }
+}
+
+/// Attend la prochaine touche pressée.
+///
+/// Le terminal n'est en mode brut que pendant l'attente : Ctrl-C reste utilisable le reste du temps.
+fn read_key() -> io::Result<KeyEvent> {
+    terminal::enable_raw_mode()?;
+    let key = loop {
+        match event::read() {
+            Ok(Event::Key(key)) if key.kind == KeyEventKind::Press => break Ok(key),
+            Ok(_) => continue,
+            Err(e) => break Err(e),
+        }
+    };
+    terminal::disable_raw_mode()?;
+    key
+}
+
+/// Attend une commande de déplacement ; les autres touches sont ignorées.
+pub fn read_command() -> io::Result<Command> {
+    loop {
+        if let Some(command) = command_for(&read_key()?) {
+            return Ok(command);
+        }
+    }
+}
+
+/// Fait saisir la réponse à un défi.
+///
+/// `Entrée` valide la saisie, ou la réponse suggérée si rien n'a été saisi ; `Échap` abandonne la partie.
+/// `on_edit` est appelé à chaque modification pour afficher la saisie en cours.
+///
+/// # Retourne
+///
+/// La réponse, ou `None` si le joueur abandonne.
+pub fn read_answer(suggestion: &str, mut on_edit: impl FnMut(&str)) -> io::Result<Option<String>> {
+    let mut answer = String::new();
+    on_edit(&answer);
+    loop {
+        let key = read_key()?;
+        match key.code {
+            KeyCode::Enter if answer.is_empty() => return Ok(Some(suggestion.to_string())),
+            KeyCode::Enter => return Ok(Some(answer)),
+            KeyCode::Esc => return Ok(None),
+            KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => return Ok(None),
+            KeyCode::Backspace => {
+                answer.pop();
+            }
+            KeyCode::Char(c) => answer.push(c),
+            _ => continue,
+        }
+        on_edit(&answer);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_command_for_keys() {
+        let key = |code| KeyEvent::new(code, KeyModifiers::NONE);
+        assert_eq!(command_for(&key(KeyCode::Up)), Some(Command::Move(RelativeDirection::Front)));
+        assert_eq!(command_for(&key(KeyCode::Char('a'))), Some(Command::Move(RelativeDirection::Left)));
+        assert_eq!(command_for(&key(KeyCode::Char('s'))), Some(Command::Move(RelativeDirection::Back)));
+        assert_eq!(command_for(&key(KeyCode::Right)), Some(Command::Move(RelativeDirection::Right)));
+        assert_eq!(command_for(&key(KeyCode::Esc)), Some(Command::Quit));
+        assert_eq!(command_for(&KeyEvent::new(KeyCode::Char('c'), KeyModifiers::CONTROL)), Some(Command::Quit));
+        assert_eq!(command_for(&key(KeyCode::Char('x'))), None);
+    }
+}
@@ -0,0 +1,157 @@
+use maze_engine::scoring::{PlayerScore, ScoreReport};
+use serde::Serialize;
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::{self, ErrorKind};
+use std::path::Path;
+
+/// Code de sortie lorsque tous les joueurs ont terminé la partie.
+pub const EXIT_FINISHED: i32 = 0;
+/// Code de sortie lorsqu'au moins un joueur s'est arrêté sur une erreur.
+pub const EXIT_FAILED: i32 = 1;
+/// Code de sortie lorsque les arguments de la ligne de commande sont invalides.
+pub const EXIT_USAGE: i32 = 2;
+/// Code de sortie lorsqu'un joueur humain a quitté la partie (mode manuel).
+pub const EXIT_QUIT: i32 = 3;
+
+/// Fin de partie d'un joueur.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Outcome {
+    /// Le serveur a fermé la connexion : le joueur est sorti du labyrinthe.
+    Finished,
+    /// Le joueur humain a quitté la partie.
+    Quit,
+    /// Le joueur s'est arrêté sur une erreur (connexion, protocole...).
+    Failed,
+}
+
+impl Outcome {
+    /// Fin de partie correspondant à la fermeture de la connexion par le serveur.
+    ///
+    /// Seule une fermeture propre (fin de flux) une fois la partie commencée termine la partie ;
+    /// une connexion réinitialisée ou interrompue, ou fermée avant la première vue radar, est une erreur (`None`).
+    pub fn from_disconnection(kind: ErrorKind, game_started: bool) -> Option<Self> {
+        (kind == ErrorKind::UnexpectedEof && game_started).then_some(Outcome::Finished)
+    }
+}
+
+/// Bilan de la partie d'un joueur.
+#[derive(Debug, Clone, Serialize)]
+pub struct PlayerSummary {
+    pub player: String,
+    pub outcome: Outcome,
+    /// Erreur ayant interrompu le joueur, si `outcome` vaut `Failed`.
+    pub error: Option<String>,
+    /// Nombre d'`ActionError` reçues, par type.
+    pub errors: BTreeMap<String, u32>,
+    /// Détail du score du joueur (déplacements, défis, secours, durée).
+    pub score: PlayerScore,
+}
+
+impl PlayerSummary {
+    /// Bilan d'un joueur qui n'a pas pu jouer.
+    pub fn failed(player: &str, error: impl ToString) -> Self {
+        Self {
+            player: player.to_string(),
+            outcome: Outcome::Failed,
+            error: Some(error.to_string()),
+            errors: BTreeMap::new(),
+            score: PlayerScore::default(),
+        }
+    }
+}
+
+/// Bilan de la partie de l'équipe.
+#[derive(Debug, Clone, Serialize)]
+pub struct GameSummary {
+    pub team: String,
+    pub duration_secs: f64,
+    pub players: Vec<PlayerSummary>,
+    /// Score de l'équipe et détail par identifiant de joueur.
+    pub score: ScoreReport,
+}
+
+impl GameSummary {
+    /// Code de sortie du client : un échec l'emporte sur un abandon, qui l'emporte sur une partie terminée.
+    pub fn exit_code(&self) -> i32 {
+        let outcomes: Vec<Outcome> = self.players.iter().map(|player| player.outcome).collect();
+        if outcomes.contains(&Outcome::Failed) {
+            EXIT_FAILED
+        } else if outcomes.contains(&Outcome::Quit) {
+            EXIT_QUIT
+        } else {
+            EXIT_FINISHED
+        }
+    }
+
+    /// Affiche le bilan dans la console.
+    pub fn print(&self) {
+        println!(
+            "[Summary] Team {} played for {:.1}s, team score {} ({:.2} moves per player)",
+            self.team, self.duration_secs, self.score.team_score, self.score.average_moves
+        );
+        for player in &self.players {
+            println!(
+                "[Summary] {}: {:?}, {} moves ({} failed), {}/{} challenges solved, {} rescues, {:.1}s, errors {:?}{}",
+                player.player,
+                player.outcome,
+                player.score.moves,
+                player.score.failed_moves,
+                player.score.challenges_solved,
+                player.score.challenge_attempts,
+                player.score.rescues_performed,
+                player.score.elapsed_secs,
+                player.errors,
+                player.error.as_ref().map(|e| format!(" ({})", e)).unwrap_or_default()
+            );
+        }
+    }
+
+    /// Écrit le bilan au format JSON.
+    pub fn write_json(&self, file: impl AsRef<Path>) -> io::Result<()> {
+        let json = serde_json::to_string_pretty(self).map_err(io::Error::other)?;
+        fs::write(file, json + "\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use maze_engine::scoring::ScoreManager;
+
+    fn game(outcomes: &[Outcome]) -> GameSummary {
+        GameSummary {
+            team: "team".to_string(),
+            duration_secs: 0.0,
+            players: outcomes
+                .iter()
+                .map(|&outcome| PlayerSummary { outcome, ..PlayerSummary::failed("player", "") })
+                .collect(),
+            score: ScoreManager::new(1).report(),
+        }
+    }
+
+    #[test]
+    fn test_outcome_from_disconnection() {
+        assert_eq!(Outcome::from_disconnection(ErrorKind::UnexpectedEof, true), Some(Outcome::Finished));
+        assert_eq!(Outcome::from_disconnection(ErrorKind::UnexpectedEof, false), None);
+        assert_eq!(Outcome::from_disconnection(ErrorKind::ConnectionReset, true), None);
+        assert_eq!(Outcome::from_disconnection(ErrorKind::ConnectionAborted, true), None);
+    }
+
+    #[test]
+    fn test_exit_code() {
+        assert_eq!(game(&[Outcome::Finished, Outcome::Finished]).exit_code(), EXIT_FINISHED);
+        assert_eq!(game(&[Outcome::Finished, Outcome::Quit]).exit_code(), EXIT_QUIT);
+        assert_eq!(game(&[Outcome::Quit, Outcome::Failed]).exit_code(), EXIT_FAILED);
+    }
+
+    #[test]
+    fn test_summary_json() {
+        let json = serde_json::to_value(game(&[Outcome::Finished])).unwrap();
+        assert_eq!(json["players"][0]["outcome"], "finished");
+        assert_eq!(json["players"][0]["score"]["moves"], 0);
+        assert_eq!(json["score"]["team_score"], 0);
+    }
+}
@@ -0,0 +1,128 @@
+use crossterm::cursor::{Hide, MoveTo, Show};
+use crossterm::style::Print;
+use crossterm::terminal::{self, Clear, ClearType, EnterAlternateScreen, LeaveAlternateScreen};
+use crossterm::{execute, queue};
+use std::collections::{BTreeMap, VecDeque};
+use std::io::{self, IsTerminal, Write};
+use std::sync::{Arc, Mutex};
+use maze_engine::log;
+
+/// Nombre d'indices conservés par joueur.
+const RECENT_HINTS: usize = 4;
+
+/// État affiché pour un joueur.
+#[derive(Default)]
+pub struct PlayerPanel {
+    /// Dernière vue radar reçue.
+    pub radar: Option<[[char; 7]; 7]>,
+    /// Position du joueur dans son repère.
+    pub position: (i32, i32),
+    /// Nombre de déplacements envoyés.
+    pub moves: u32,
+    /// Score de l'équipe, vu par ce joueur.
+    pub score: u32,
+    /// Fenêtre de la carte globale centrée sur le joueur, coéquipiers compris (voir `render_text_window`).
+    pub map: Vec<String>,
+    /// Derniers indices reçus, du plus récent au plus ancien.
+    pub hints: VecDeque<String>,
+    /// Défi en cours, s'il y en a un.
+    pub challenge: Option<String>,
+    /// Dernier événement notable (erreur d'action, fin de partie...).
+    pub status: String,
+}
+
+impl PlayerPanel {
+    /// Ajoute un indice à la liste des indices récents.
+    pub fn push_hint(&mut self, hint: String) {
+        self.hints.push_front(hint);
+        self.hints.truncate(RECENT_HINTS);
+    }
+}
+
+/// Interface plein écran du client, partagée par les threads des joueurs.
+///
+/// L'interface est dessinée sur la sortie d'erreur : la sortie standard, où sont écrits les journaux,
+/// peut ainsi être redirigée vers un fichier. Si elle ne l'est pas, les journaux sont coupés tant que
+/// l'interface est affichée. Chaque mise à jour redessine l'écran complet.
+#[derive(Clone)]
+pub struct Tui {
+    players: Arc<Mutex<BTreeMap<String, PlayerPanel>>>,
+}
+
+impl Tui {
+    /// Bascule le terminal en plein écran.
+    pub fn start() -> io::Result<Self> {
+        if io::stdout().is_terminal() {
+            // Écrits sur le terminal, les journaux effaceraient l'interface : ils sont coupés jusqu'à sa fermeture.
+            eprintln!("[Tui] Logs are disabled while the display is active; redirect stdout to a file to keep them.");
+            log::set_enabled(false);
+        }
+        execute!(io::stderr(), EnterAlternateScreen, Hide)?;
+        Ok(Self {
+            players: Arc::new(Mutex::new(BTreeMap::new())),
+        })
+    }
+
+    /// Restaure le terminal.
+    pub fn stop(&self) -> io::Result<()> {
+        log::set_enabled(true);
+        execute!(io::stderr(), Show, LeaveAlternateScreen)
+    }
+
+    /// Taille (colonnes, lignes) de la fenêtre de carte qui tient à l'écran.
+    pub fn map_window() -> (usize, usize) {
+        let (columns, rows) = terminal::size().unwrap_or((80, 24));
+        // À gauche le radar (9 colonnes) ; en bas la liste des joueurs.
+        ((columns as usize).saturating_sub(12).max(7), (rows as usize).saturating_sub(14).max(7))
+    }
+
+    /// Met à jour l'état d'un joueur puis redessine l'écran.
+    pub fn update(&self, player: &str, update: impl FnOnce(&mut PlayerPanel)) {
+        let mut players = self.players.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        update(players.entry(player.to_string()).or_default());
+        if let Err(e) = Self::draw(&players) {
+            log!("[Tui] Failed to draw: {}", e);
+        }
+    }
+
+    fn draw(players: &BTreeMap<String, PlayerPanel>) -> io::Result<()> {
+        let mut lines: Vec<String> = Vec::new();
+        // Le premier joueur est suivi en détail ; les autres sont résumés.
+        if let Some((name, panel)) = players.iter().next() {
+            lines.push(format!("Radar ({})  Carte globale ({} en {:?})", name, name, panel.position));
+            let radar: Vec<String> = match panel.radar {
+                Some(radar) => radar.iter().map(|row| row.iter().collect()).collect(),
+                None => vec![String::new(); 7],
+            };
+            let rows = panel.map.len().max(radar.len());
+            for row in 0..rows {
+                let radar_row = radar.get(row).map(String::as_str).unwrap_or("");
+                let map_row = panel.map.get(row).map(String::as_str).unwrap_or("");
+                lines.push(format!("{:<7}  | {}", radar_row, map_row));
+            }
+        }
+        lines.push(String::new());
+        lines.push(format!("{:<10} {:>10} {:>6} {:>7}  {:<24} {}", "Joueur", "Position", "Moves", "Score", "Défi", "Événement"));
+        for (name, panel) in players {
+            lines.push(format!(
+                "{:<10} {:>10} {:>6} {:>7}  {:<24} {}",
+                name,
+                format!("{:?}", panel.position),
+                panel.moves,
+                panel.score,
+                panel.challenge.as_deref().unwrap_or("-"),
+                panel.status
+            ));
+            for hint in &panel.hints {
+                lines.push(format!("{:<10} indice : {}", "", hint));
+            }
+        }
+
+        let mut stderr = io::stderr();
+        queue!(stderr, MoveTo(0, 0), Clear(ClearType::All))?;
+        for (row, line) in lines.iter().enumerate() {
+            queue!(stderr, MoveTo(0, row as u16), Print(line))?;
+        }
+        stderr.flush()
+    }
+}
@@ -163,9 +163,9 @@ pub fn decode_radar_view_binary(binary_radar_view: Vec<u8>) -> [[char; 7]; 7] {
                 if radar_view[row][column - 1] == '#' && radar_view[row][column + 1] == '#' {
                     radar_view[row][column] = '#';
                 }
-            } else if column > 0 && radar_view[row][column - 1] == '#' {
-                radar_view[row][column] = '#';
-            } else if column < 6 && radar_view[row][column + 1] == '#' {
+            } else if (column > 0 && radar_view[row][column - 1] == '#')
+                || (column < 6 && radar_view[row][column + 1] == '#')
+            {
                 radar_view[row][column] = '#';
             }
         }
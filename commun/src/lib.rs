@@ -14,4 +14,5 @@ pub use {
 pub mod structs;
 pub mod utils;
 pub mod decodage;
-pub mod encodage;
\ No newline at end of file
+pub mod encodage;
+pub mod rules;
\ No newline at end of file
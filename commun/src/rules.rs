@@ -0,0 +1,4 @@
+//! Règles du jeu partagées par le client et le serveur de test.
+
+/// Déplacements ajoutés au score du joueur qui abandonne un défi.
+pub const CHALLENGE_ABANDON_PENALTY: u32 = 10;
@@ -17,7 +17,7 @@ pub fn generate_random_number(range: RangeInclusive<i32>) -> i32 {
     
 }
 
-pub fn debug_binary(vec: &Vec<u8>) -> String {
+pub fn debug_binary(vec: &[u8]) -> String {
     vec.iter()
         .map(|byte| format!("{:08b}", byte)) // Convertir chaque octet en une string binaire de 8 bits
         .collect::<Vec<String>>() // Collecter dans un Vec<String>
@@ -47,7 +47,7 @@ impl ChallengeManager {
     /// manager.set_secret(1, 42);
     /// ```
     pub fn set_secret(&mut self, player_id: u32, secret: u64) {
-        println!("[ChallengeManager] Storing secret for player {}: {}", player_id, secret);
+        crate::log!("[ChallengeManager] Storing secret for player {}: {}", player_id, secret);
         self.secrets.insert(player_id, secret);
     }
 
@@ -80,7 +80,7 @@ impl ChallengeManager {
             .map(|player_id| self.secrets.get(player_id).copied().unwrap_or(0))
             .sum();
         let result = sum % modulo;
-        println!(
+        crate::log!(
             "[ChallengeManager] Solving SecretModulo: sum = {}, modulo = {}, result = {}",
             sum, modulo, result
         );
@@ -147,6 +147,12 @@ impl ChallengeManager {
     }
 }
 
+impl Default for ChallengeManager {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,295 @@
+use crate::global_map::{GlobalMap, Knowledge};
+use std::collections::HashMap;
+use std::fmt::Write as _;
+use std::fs;
+use std::io;
+use std::path::Path;
+
+/// Taille (en pixels) d'une cellule dans l'export SVG.
+const CELL_SIZE: i32 = 24;
+/// Taille (en pixels) d'une case de la grille (cellule ou passage) dans l'export SVG.
+const HALF_CELL: i32 = CELL_SIZE / 2;
+
+/// Rectangle de la grille couvrant les cellules explorées et leurs murs extérieurs, bornes incluses.
+fn grid_extent(map: &GlobalMap) -> Option<((i32, i32), (i32, i32))> {
+    let bounds = map.explored_bounds()?;
+    let (min_x, min_y) = map.grid_position((bounds.min_x, bounds.min_y));
+    let (max_x, max_y) = map.grid_position((bounds.max_x, bounds.max_y));
+    Some(((min_x - 1, min_y - 1), (max_x + 1, max_y + 1)))
+}
+
+/// Produit la carte explorée au format texte des labyrinthes (`•`, `-`, `|`, `#` pour l'inconnu).
+///
+/// Les cellules remarquables sont superposées à la carte :
+/// - `.` : cellule du chemin parcouru ;
+/// - `H` : cellule sur laquelle un indice a été reçu ;
+/// - `S` : départ du chemin ;
+/// - `G` : sortie ;
+/// - `P` : position actuelle (fin du chemin).
+///
+/// # Arguments
+///
+/// * `map` - La carte à exporter.
+/// * `path` - Les cellules parcourues par le joueur, dans l'ordre (voir [`crate::navigation::Navigator::path`]).
+///
+/// # Exemple
+///
+/// ```
+/// use maze_engine::export::render_text;
+/// use maze_engine::global_map::GlobalMap;
+///
+/// let mut map = GlobalMap::new(0, 0);
+/// let mut radar_view = [['#'; 7]; 7];
+/// for (row, line) in ["•-•", "| |", "•-•"].iter().enumerate() {
+///     for (column, c) in line.chars().enumerate() {
+///         radar_view[row + 2][column + 2] = c;
+///     }
+/// }
+/// map.update_from_radar(&radar_view, (0, 0));
+/// assert_eq!(render_text(&map, &[(0, 0)]), "•-•\n|P|\n•-•\n");
+/// ```
+pub fn render_text(map: &GlobalMap, path: &[(i32, i32)]) -> String {
+    let Some((min, max)) = grid_extent(map) else {
+        return String::new();
+    };
+    render_rows(map, path, &[], min, max)
+        .into_iter()
+        .map(|row| row + "\n")
+        .collect()
+}
+
+/// Produit, au même format que [`render_text`], une fenêtre de `columns x rows` cases de grille
+/// centrée sur une cellule (par exemple la position du joueur, pour un affichage en direct).
+///
+/// Les `markers` (par exemple les coéquipiers) sont dessinés sur leur cellule, sous le joueur.
+///
+/// # Exemple
+///
+/// ```
+/// use maze_engine::export::render_text_window;
+/// use maze_engine::global_map::GlobalMap;
+///
+/// let map = GlobalMap::new(0, 0);
+/// assert_eq!(render_text_window(&map, &[(0, 0)], &[], (0, 0), 3, 1), vec!["#P#"]);
+/// assert_eq!(render_text_window(&map, &[(0, 0)], &[((1, 0), '2')], (0, 0), 5, 1), vec!["##P#2"]);
+/// ```
+pub fn render_text_window(
+    map: &GlobalMap,
+    path: &[(i32, i32)],
+    markers: &[((i32, i32), char)],
+    center: (i32, i32),
+    columns: usize,
+    rows: usize,
+) -> Vec<String> {
+    if columns == 0 || rows == 0 {
+        return Vec::new();
+    }
+    let (center_x, center_y) = map.grid_position(center);
+    let min = (center_x - columns as i32 / 2, center_y - rows as i32 / 2);
+    let max = (min.0 + columns as i32 - 1, min.1 + rows as i32 - 1);
+    render_rows(map, path, markers, min, max)
+}
+
+/// Produit les lignes de texte couvrant le rectangle de grille `[min, max]`, cellules remarquables comprises.
+fn render_rows(
+    map: &GlobalMap,
+    path: &[(i32, i32)],
+    markers: &[((i32, i32), char)],
+    min: (i32, i32),
+    max: (i32, i32),
+) -> Vec<String> {
+    let mut overlay: HashMap<(i32, i32), char> = HashMap::new();
+    for &cell in path {
+        overlay.insert(map.grid_position(cell), '.');
+    }
+    for cell in map.hint_cells() {
+        overlay.insert(map.grid_position(cell), 'H');
+    }
+    if let Some(&start) = path.first() {
+        overlay.insert(map.grid_position(start), 'S');
+    }
+    if let Some(goal) = map.goal() {
+        overlay.insert(map.grid_position(goal), 'G');
+    }
+    for &(cell, marker) in markers {
+        overlay.insert(map.grid_position(cell), marker);
+    }
+    if let Some(&current) = path.last() {
+        overlay.insert(map.grid_position(current), 'P');
+    }
+
+    (min.1..=max.1)
+        .map(|y| {
+            (min.0..=max.0)
+                .map(|x| overlay.get(&(x, y)).copied().unwrap_or_else(|| map.get(x, y)))
+                .collect()
+        })
+        .collect()
+}
+
+/// Produit une image SVG de la carte explorée.
+///
+/// Les cellules inconnues sont grisées, les murs connus tracés en noir ; le chemin parcouru est
+/// tracé en bleu, du départ (point bleu) à la position actuelle (point rouge). La sortie est
+/// représentée par un point vert et les indices par des points orange.
+///
+/// # Arguments
+///
+/// * `map` - La carte à exporter.
+/// * `path` - Les cellules parcourues par le joueur, dans l'ordre.
+pub fn render_svg(map: &GlobalMap, path: &[(i32, i32)]) -> String {
+    let Some(((min_x, min_y), (max_x, max_y))) = grid_extent(map) else {
+        return "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"0\" height=\"0\"/>\n".to_string();
+    };
+    let origin = map.grid_position((0, 0));
+    // Les cases de la grille alternent murs et cellules : une cellule occupe le carré centré sur sa case.
+    let pixel = |(x, y): (i32, i32)| ((x - min_x) * HALF_CELL, (y - min_y) * HALF_CELL);
+    let (width, height) = pixel((max_x, max_y));
+
+    let mut svg = String::new();
+    let _ = writeln!(
+        svg,
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" width=\"{}\" height=\"{}\" viewBox=\"0 0 {} {}\">",
+        width, height, width, height
+    );
+    let _ = writeln!(svg, "  <rect width=\"{}\" height=\"{}\" fill=\"white\"/>", width, height);
+
+    for y in min_y..=max_y {
+        for x in min_x..=max_x {
+            let (dx, dy) = (x - origin.0, y - origin.1);
+            let (px, py) = pixel((x, y));
+            match (map.knowledge(x, y), dx % 2 == 0, dy % 2 == 0) {
+                // Cellule inconnue.
+                (Knowledge::Unknown, true, true) => {
+                    let _ = writeln!(
+                        svg,
+                        "  <rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" fill=\"#cccccc\"/>",
+                        px - HALF_CELL, py - HALF_CELL, CELL_SIZE, CELL_SIZE
+                    );
+                }
+                // Mur horizontal, entre deux cellules superposées.
+                (Knowledge::Wall, true, false) => {
+                    let _ = writeln!(
+                        svg,
+                        "  <line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"black\" stroke-width=\"2\"/>",
+                        px - HALF_CELL, py, px + HALF_CELL, py
+                    );
+                }
+                // Mur vertical, entre deux cellules côte à côte.
+                (Knowledge::Wall, false, true) => {
+                    let _ = writeln!(
+                        svg,
+                        "  <line x1=\"{}\" y1=\"{}\" x2=\"{}\" y2=\"{}\" stroke=\"black\" stroke-width=\"2\"/>",
+                        px, py - HALF_CELL, px, py + HALF_CELL
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+
+    if !path.is_empty() {
+        let points: Vec<String> = path
+            .iter()
+            .map(|&cell| {
+                let (px, py) = pixel(map.grid_position(cell));
+                format!("{},{}", px, py)
+            })
+            .collect();
+        let _ = writeln!(
+            svg,
+            "  <polyline points=\"{}\" fill=\"none\" stroke=\"#1f77b4\" stroke-width=\"2\"/>",
+            points.join(" ")
+        );
+    }
+
+    let mut marker = |cell: (i32, i32), color: &str| {
+        let (px, py) = pixel(map.grid_position(cell));
+        let _ = writeln!(
+            svg,
+            "  <circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"{}\"/>",
+            px, py, CELL_SIZE / 4, color
+        );
+    };
+    for cell in map.hint_cells() {
+        marker(cell, "orange");
+    }
+    if let Some(goal) = map.goal() {
+        marker(goal, "green");
+    }
+    if let Some(&start) = path.first() {
+        marker(start, "#1f77b4");
+    }
+    if let Some(&current) = path.last() {
+        marker(current, "red");
+    }
+
+    svg.push_str("</svg>\n");
+    svg
+}
+
+/// Écrit la carte au format texte dans un fichier (voir [`render_text`]).
+pub fn write_text(map: &GlobalMap, path: &[(i32, i32)], file: impl AsRef<Path>) -> io::Result<()> {
+    fs::write(file, render_text(map, path))
+}
+
+/// Écrit la carte au format SVG dans un fichier (voir [`render_svg`]).
+pub fn write_svg(map: &GlobalMap, path: &[(i32, i32)], file: impl AsRef<Path>) -> io::Result<()> {
+    fs::write(file, render_svg(map, path))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Carte d'un couloir horizontal de deux cellules, la sortie à droite.
+    fn corridor_map() -> GlobalMap {
+        let mut radar_view = [['#'; 7]; 7];
+        for (row, line) in ["•-•-•", "|   |", "•-•-•"].iter().enumerate() {
+            for (column, c) in line.chars().enumerate() {
+                radar_view[row + 2][column + 2] = c;
+            }
+        }
+        let mut map = GlobalMap::new(0, 0);
+        map.update_from_radar(&radar_view, (0, 0));
+        map.set_goal((1, 0));
+        map
+    }
+
+    #[test]
+    fn test_render_text_overlays() {
+        let mut map = corridor_map();
+        map.record_hint((0, 0));
+        assert_eq!(render_text(&map, &[]), "•-•-•\n|H G|\n•-•-•\n");
+        assert_eq!(render_text(&map, &[(0, 0), (1, 0)]), "•-•-•\n|S P|\n•-•-•\n");
+    }
+
+    #[test]
+    fn test_render_text_empty_map() {
+        assert_eq!(render_text(&GlobalMap::new(0, 0), &[]), "");
+    }
+
+    #[test]
+    fn test_render_svg() {
+        let svg = render_svg(&corridor_map(), &[(0, 0), (1, 0)]);
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.trim_end().ends_with("</svg>"));
+        // Six murs : deux en haut, deux en bas et un à chaque extrémité du couloir.
+        assert_eq!(svg.matches("<line").count(), 6);
+        assert!(svg.contains("<polyline points=\"12,12 36,12\""));
+        assert!(svg.contains("fill=\"green\""));
+    }
+
+    #[test]
+    fn test_write_files() {
+        let directory = std::env::temp_dir();
+        let text_file = directory.join(format!("maze_export_{}.txt", std::process::id()));
+        let svg_file = directory.join(format!("maze_export_{}.svg", std::process::id()));
+        write_text(&corridor_map(), &[(0, 0)], &text_file).unwrap();
+        write_svg(&corridor_map(), &[(0, 0)], &svg_file).unwrap();
+        assert_eq!(fs::read_to_string(&text_file).unwrap(), "•-•-•\n|P G|\n•-•-•\n");
+        assert!(fs::read_to_string(&svg_file).unwrap().contains("<svg"));
+        let _ = fs::remove_file(text_file);
+        let _ = fs::remove_file(svg_file);
+    }
+}
@@ -1,29 +1,93 @@
-use std::collections::HashSet;
+use std::collections::HashMap;
+use std::iter::repeat_n;
 
-/// Largeur maximale de la carte.
+/// Largeur initiale de la grille (la carte s'agrandit ensuite à la demande).
 pub const MAP_WIDTH: usize = 20;
-/// Hauteur maximale de la carte.
+/// Hauteur initiale de la grille (la carte s'agrandit ensuite à la demande).
 pub const MAP_HEIGHT: usize = 20;
 
 /// Liste des caractères représentant un mur.
-const WALLS: [char; 3] = ['•', '-', '|'];
+pub(crate) const WALLS: [char; 3] = ['•', '-', '|'];
+
+/// État de connaissance d'une case de la grille (cellule, passage ou coin).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Knowledge {
+    /// Jamais observée.
+    #[default]
+    Unknown,
+    /// Observée et franchissable.
+    Open,
+    /// Observée et bloquée par un mur.
+    Wall,
+}
+
+/// Informations associées à une cellule.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CellInfo {
+    /// Nombre de vues radar reçues depuis cette cellule.
+    pub visits: u32,
+    /// Dernière vue radar (numéro de tour, voir [`GlobalMap::tick`]) dans laquelle la cellule apparaissait.
+    pub last_seen: Option<u64>,
+    /// Un indice a été reçu sur cette cellule.
+    pub hint_seen: bool,
+}
 
 /// **Carte globale du labyrinthe**.
 ///
 /// Cette structure permet de représenter la carte globale en tenant compte des mises à jour reçues via des
-/// `RadarView`. Elle stocke une grille de caractères ainsi qu'un ensemble des positions déjà explorées.
+/// `RadarView`. Chaque case de la grille (cellule ou passage) est inconnue, ouverte ou murée
+/// (voir [`Knowledge`]) : une zone jamais observée n'est jamais confondue avec un mur.
+/// Chaque cellule porte en outre des informations de parcours (voir [`CellInfo`]).
+///
+/// Les coordonnées de grille sont signées et la grille s'agrandit à la demande dans toutes les directions :
+/// aucune écriture n'est perdue, quelle que soit la direction prise par le joueur. Une fois la taille du
+/// labyrinthe connue (indice `GridSize`, voir [`GlobalMap::set_grid_size`]), la carte est restreinte aux
+/// cellules qui peuvent encore appartenir au labyrinthe.
 pub struct GlobalMap {
-    grid: Vec<Vec<char>>,             // Carte stockant les murs et passages.
-    explored: HashSet<(usize, usize)>, // Ensemble des cases déjà explorées.
+    grid: Vec<Vec<Knowledge>>,          // État de chaque case de la grille.
+    grid_min: (i32, i32),               // Coordonnées de grille de `grid[0][0]`.
+    cells: HashMap<(i32, i32), CellInfo>, // Informations de parcours, par cellule.
+    tick: u64,                          // Nombre de vues radar intégrées.
+    origin: (i32, i32),                 // Position dans la grille de la cellule de départ (0, 0).
+    goal: Option<(i32, i32)>,           // Cellule de sortie, si elle a été repérée.
+    grid_size: Option<(u32, u32)>,      // Taille du labyrinthe en cellules (colonnes, lignes), si connue.
+    cell_bounds: Option<CellBounds>,    // Plus petit rectangle contenant les cellules explorées.
+}
+
+/// Rectangle de cellules (bornes incluses).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellBounds {
+    pub min_x: i32,
+    pub max_x: i32,
+    pub min_y: i32,
+    pub max_y: i32,
+}
+
+impl CellBounds {
+    fn point((x, y): (i32, i32)) -> Self {
+        Self { min_x: x, max_x: x, min_y: y, max_y: y }
+    }
+
+    fn include(&mut self, (x, y): (i32, i32)) {
+        self.min_x = self.min_x.min(x);
+        self.max_x = self.max_x.max(x);
+        self.min_y = self.min_y.min(y);
+        self.max_y = self.max_y.max(y);
+    }
+
+    /// Indique si la cellule se trouve dans le rectangle.
+    pub fn contains(&self, (x, y): (i32, i32)) -> bool {
+        (self.min_x..=self.max_x).contains(&x) && (self.min_y..=self.max_y).contains(&y)
+    }
 }
 
 impl GlobalMap {
-    /// Crée une carte vide remplie de `#` (zones inconnues) et place le joueur à la position de départ.
+    /// Crée une carte vide (toutes les cases sont inconnues) dont la cellule de départ est à la position donnée.
     ///
     /// # Arguments
     ///
-    /// * `start_x` - Position horizontale de départ.
-    /// * `start_y` - Position verticale de départ.
+    /// * `start_x` - Position horizontale de départ dans la grille.
+    /// * `start_y` - Position verticale de départ dans la grille.
     ///
     /// # Exemples
     ///
@@ -33,16 +97,34 @@ impl GlobalMap {
     /// let map = GlobalMap::new(3, 4);
     /// // La position initiale du joueur est (3, 4).
     /// ```
-    pub fn new(start_x: usize, start_y: usize) -> Self {
-        let mut grid = vec![vec!['#'; MAP_WIDTH]; MAP_HEIGHT];
-        grid[start_y][start_x] = 'P'; // Position initiale du joueur
-
+    pub fn new(start_x: i32, start_y: i32) -> Self {
         Self {
-            grid,
-            explored: HashSet::new(),
+            grid: vec![vec![Knowledge::Unknown; MAP_WIDTH]; MAP_HEIGHT],
+            grid_min: (start_x - MAP_WIDTH as i32 / 2, start_y - MAP_HEIGHT as i32 / 2),
+            cells: HashMap::new(),
+            tick: 0,
+            origin: (start_x, start_y),
+            goal: None,
+            grid_size: None,
+            cell_bounds: None,
         }
     }
 
+    /// Retourne l'état de connaissance d'une case de la grille.
+    ///
+    /// Une case hors de la grille est inconnue.
+    pub fn knowledge(&self, x: i32, y: i32) -> Knowledge {
+        let (column, row) = (x - self.grid_min.0, y - self.grid_min.1);
+        if column < 0 || row < 0 {
+            return Knowledge::Unknown;
+        }
+        self.grid
+            .get(row as usize)
+            .and_then(|line| line.get(column as usize))
+            .copied()
+            .unwrap_or_default()
+    }
+
     /// Vérifie si une case a déjà été explorée.
     ///
     /// # Arguments
@@ -52,9 +134,9 @@ impl GlobalMap {
     ///
     /// # Retourne
     ///
-    /// `true` si la case (x, y) est dans l'ensemble des cases explorées, sinon `false`.
-    pub fn is_visited(&self, x: usize, y: usize) -> bool {
-        self.explored.contains(&(x, y))
+    /// `true` si l'état de la case (x, y) est connu (ouvert ou mur), sinon `false`.
+    pub fn is_visited(&self, x: i32, y: i32) -> bool {
+        self.knowledge(x, y) != Knowledge::Unknown
     }
 
     /// Vérifie si une case contient un mur.
@@ -66,50 +148,416 @@ impl GlobalMap {
     ///
     /// # Retourne
     ///
-    /// `true` si le caractère de la case correspond à l'un des caractères de mur définis dans `WALLS`, sinon `false`.
-    pub fn is_wall(&self, x: usize, y: usize) -> bool {
-        WALLS.contains(&self.grid[y][x])
+    /// `true` si un mur a été observé sur la case, sinon `false` (case ouverte ou inconnue).
+    pub fn is_wall(&self, x: i32, y: i32) -> bool {
+        self.knowledge(x, y) == Knowledge::Wall
+    }
+
+    /// Retourne la représentation textuelle d'une case de la grille, au format des vues radar.
+    ///
+    /// Les cases inconnues sont représentées par `#`, la sortie par `G`.
+    pub fn get(&self, x: i32, y: i32) -> char {
+        let (dx, dy) = (x - self.origin.0, y - self.origin.1);
+        match self.knowledge(x, y) {
+            Knowledge::Unknown => '#',
+            Knowledge::Wall if dx % 2 != 0 && dy % 2 != 0 => '•',
+            Knowledge::Wall if dy % 2 != 0 => '-',
+            Knowledge::Wall => '|',
+            Knowledge::Open if self.goal.is_some() && self.grid_to_cell(x, y) == self.goal => 'G',
+            Knowledge::Open => ' ',
+        }
     }
 
-    /// Ajoute un mur à la carte et marque la case comme explorée.
+    /// Ajoute un mur à la carte, ce qui marque la case comme explorée.
     ///
     /// # Arguments
     ///
     /// * `x` - Position horizontale.
     /// * `y` - Position verticale.
-    /// * `wall_type` - Caractère représentant le type de mur (ex. '-' ou '|').
-    pub fn set_wall(&mut self, x: usize, y: usize, wall_type: char) {
-        if x < MAP_WIDTH && y < MAP_HEIGHT {
-            self.grid[y][x] = wall_type;
-            self.explored.insert((x, y));
-        }
+    pub fn set_wall(&mut self, x: i32, y: i32) {
+        self.write(x, y, Knowledge::Wall);
     }
 
-    /// Marque une case comme explorée et y assigne un contenu.
+    /// Marque une case comme explorée et franchissable.
     ///
     /// # Arguments
     ///
     /// * `x` - Position horizontale.
     /// * `y` - Position verticale.
-    /// * `cell_content` - Caractère indiquant le contenu de la case (ex. ' ' pour vide).
-    pub fn set_explored(&mut self, x: usize, y: usize, cell_content: char) {
-        if x < MAP_WIDTH && y < MAP_HEIGHT {
-            self.grid[y][x] = cell_content;
-            self.explored.insert((x, y));
+    pub fn set_explored(&mut self, x: i32, y: i32) {
+        self.write(x, y, Knowledge::Open);
+    }
+
+    /// Retourne l'état de connaissance d'une cellule (relative au départ).
+    pub fn cell_state(&self, cell: (i32, i32)) -> Knowledge {
+        let (x, y) = self.cell_to_grid(cell);
+        self.knowledge(x, y)
+    }
+
+    /// Retourne l'état de connaissance du passage entre deux cellules.
+    ///
+    /// Deux cellules non adjacentes sont toujours séparées par un mur.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use maze_engine::global_map::{GlobalMap, Knowledge};
+    ///
+    /// let mut map = GlobalMap::new(0, 0);
+    /// assert_eq!(map.passage_state((0, 0), (1, 0)), Knowledge::Unknown);
+    /// map.block_passage((0, 0), (1, 0));
+    /// assert_eq!(map.passage_state((0, 0), (1, 0)), Knowledge::Wall);
+    /// ```
+    pub fn passage_state(&self, from: (i32, i32), to: (i32, i32)) -> Knowledge {
+        match self.passage_to_grid(from, to) {
+            Some((x, y)) => self.knowledge(x, y),
+            None => Knowledge::Wall,
+        }
+    }
+
+    /// Retourne les informations de parcours d'une cellule (valeurs par défaut si elle n'a jamais été vue).
+    pub fn cell_info(&self, cell: (i32, i32)) -> CellInfo {
+        self.cells.get(&cell).copied().unwrap_or_default()
+    }
+
+    /// Enregistre le passage du joueur sur une cellule.
+    pub fn record_visit(&mut self, cell: (i32, i32)) {
+        self.cells.entry(cell).or_default().visits += 1;
+    }
+
+    /// Enregistre la réception d'un indice sur une cellule.
+    pub fn record_hint(&mut self, cell: (i32, i32)) {
+        self.cells.entry(cell).or_default().hint_seen = true;
+    }
+
+    /// Retourne les cellules sur lesquelles un indice a été reçu, triées.
+    pub fn hint_cells(&self) -> Vec<(i32, i32)> {
+        let mut cells: Vec<(i32, i32)> = self
+            .cells
+            .iter()
+            .filter(|(_, info)| info.hint_seen)
+            .map(|(&cell, _)| cell)
+            .collect();
+        cells.sort();
+        cells
+    }
+
+    /// Retourne les coordonnées de grille d'une cellule (relative au départ).
+    ///
+    /// Les passages se trouvent entre deux cellules, à une case de grille de chacune.
+    pub fn grid_position(&self, cell: (i32, i32)) -> (i32, i32) {
+        self.cell_to_grid(cell)
+    }
+
+    /// Retourne le nombre de vues radar intégrées, qui sert d'horloge à `CellInfo::last_seen`.
+    pub fn tick(&self) -> u64 {
+        self.tick
+    }
+
+    /// Enregistre la taille du labyrinthe (en cellules) reçue via l'indice `GridSize`.
+    ///
+    /// Les informations situées hors des cellules qui peuvent encore appartenir au labyrinthe (voir
+    /// [`GlobalMap::cell_limits`]) sont supprimées, et les écritures ultérieures hors de ces limites ignorées.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use maze_engine::global_map::GlobalMap;
+    ///
+    /// let mut map = GlobalMap::new(0, 0);
+    /// map.update_from_radar(&[[' '; 7]; 7], (0, 0));
+    /// map.set_grid_size(3, 3);
+    /// assert!(map.contains_cell((1, 1)));
+    /// assert!(!map.contains_cell((2, 0)));
+    /// ```
+    pub fn set_grid_size(&mut self, columns: u32, rows: u32) {
+        crate::log!("[GlobalMap] Grid size hint: {} columns x {} rows", columns, rows);
+        self.grid_size = Some((columns, rows));
+        let Some(limits) = self.cell_limits() else {
+            return;
+        };
+        let (min_x, min_y) = self.cell_to_grid((limits.min_x, limits.min_y));
+        let (max_x, max_y) = self.cell_to_grid((limits.max_x, limits.max_y));
+        let (min_x, min_y, max_x, max_y) = (min_x - 1, min_y - 1, max_x + 1, max_y + 1);
+
+        let grid: Vec<Vec<Knowledge>> = (min_y..=max_y)
+            .map(|y| (min_x..=max_x).map(|x| self.knowledge(x, y)).collect())
+            .collect();
+        self.grid = grid;
+        self.grid_min = (min_x, min_y);
+        self.cells.retain(|&cell, _| limits.contains(cell));
+    }
+
+    /// Retourne la taille du labyrinthe (colonnes, lignes), si elle est connue.
+    pub fn grid_size(&self) -> Option<(u32, u32)> {
+        self.grid_size
+    }
+
+    /// Retourne le plus petit rectangle contenant toutes les cellules explorées.
+    pub fn explored_bounds(&self) -> Option<CellBounds> {
+        self.cell_bounds
+    }
+
+    /// Retourne le rectangle des cellules qui peuvent appartenir au labyrinthe, une fois sa taille connue.
+    ///
+    /// Le labyrinthe compte `colonnes x lignes` cellules et contient toutes les cellules explorées : une
+    /// cellule plus éloignée que cela d'une cellule explorée ne peut pas en faire partie.
+    ///
+    /// # Retourne
+    ///
+    /// `None` tant que la taille ou la position d'au moins une cellule est inconnue (carte non bornée).
+    pub fn cell_limits(&self) -> Option<CellBounds> {
+        let (columns, rows) = self.grid_size?;
+        let bounds = self.cell_bounds?;
+        let (columns, rows) = (columns as i32, rows as i32);
+        Some(CellBounds {
+            min_x: bounds.max_x - columns + 1,
+            max_x: bounds.min_x + columns - 1,
+            min_y: bounds.max_y - rows + 1,
+            max_y: bounds.min_y + rows - 1,
+        })
+    }
+
+    /// Met à jour la carte à partir d'une vue radar 7x7 centrée sur la cellule `position`.
+    ///
+    /// Les caractères `#` (zones non définies par le radar) sont ignorés afin de ne pas écraser
+    /// une information déjà connue. Les murs sont enregistrés via `set_wall`, le reste via `set_explored`.
+    /// Chaque cellule visible est datée du numéro de la vue (`CellInfo::last_seen`).
+    ///
+    /// # Arguments
+    ///
+    /// * `radar_view` - La matrice 7x7 décodée de la vue radar.
+    /// * `position` - La cellule (relative au départ) sur laquelle se trouve le joueur.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use maze_engine::global_map::GlobalMap;
+    ///
+    /// let mut map = GlobalMap::new(10, 10);
+    /// map.update_from_radar(&[[' '; 7]; 7], (0, 0));
+    /// assert!(map.is_cell_explored((1, 1)));
+    /// assert!(map.is_passage_open((0, 0), (0, 1)));
+    /// assert_eq!(map.cell_info((1, 1)).last_seen, Some(1));
+    /// ```
+    pub fn update_from_radar(&mut self, radar_view: &[[char; 7]; 7], position: (i32, i32)) {
+        self.tick += 1;
+        let (center_x, center_y) = self.cell_to_grid(position);
+        for (row, line) in radar_view.iter().enumerate() {
+            for (column, &content) in line.iter().enumerate() {
+                if content == '#' {
+                    continue;
+                }
+                let x = center_x + column as i32 - 3;
+                let y = center_y + row as i32 - 3;
+                if WALLS.contains(&content) {
+                    self.set_wall(x, y);
+                } else {
+                    self.set_explored(x, y);
+                }
+                if let Some(cell) = self.grid_to_cell(x, y) {
+                    if self.contains_cell(cell) {
+                        self.cells.entry(cell).or_default().last_seen = Some(self.tick);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Enregistre la position de la sortie (cellule relative au départ).
+    ///
+    /// La cellule est également marquée ouverte sur la carte, afin d'être partagée
+    /// avec les coéquipiers qui consultent la même carte.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use maze_engine::global_map::GlobalMap;
+    ///
+    /// let mut map = GlobalMap::new(10, 10);
+    /// map.set_goal((1, -1));
+    /// assert_eq!(map.goal(), Some((1, -1)));
+    /// ```
+    pub fn set_goal(&mut self, cell: (i32, i32)) {
+        if self.goal != Some(cell) {
+            crate::log!("[GlobalMap] Goal recorded at {:?}", cell);
+        }
+        self.goal = Some(cell);
+        let (x, y) = self.cell_to_grid(cell);
+        self.set_explored(x, y);
+    }
+
+    /// Retourne la cellule de sortie si elle a été repérée.
+    pub fn goal(&self) -> Option<(i32, i32)> {
+        self.goal
+    }
+
+    /// Indique si une cellule (relative au départ) peut appartenir au labyrinthe.
+    ///
+    /// Tant que la taille du labyrinthe est inconnue, toutes les cellules sont acceptées.
+    pub fn contains_cell(&self, cell: (i32, i32)) -> bool {
+        self.cell_limits().is_none_or(|limits| limits.contains(cell))
+    }
+
+    /// Vérifie si une cellule (relative au départ) a déjà été observée par le radar.
+    pub fn is_cell_explored(&self, cell: (i32, i32)) -> bool {
+        self.cell_state(cell) != Knowledge::Unknown
+    }
+
+    /// Vérifie si le passage entre deux cellules adjacentes est connu et ouvert.
+    ///
+    /// # Retourne
+    ///
+    /// `true` uniquement si le passage a été observé et ne contient pas de mur.
+    /// Un passage inconnu est considéré comme fermé (voir [`GlobalMap::passage_state`] pour les distinguer).
+    pub fn is_passage_open(&self, from: (i32, i32), to: (i32, i32)) -> bool {
+        self.passage_state(from, to) == Knowledge::Open
+    }
+
+    /// Vérifie si un mur est connu entre deux cellules adjacentes.
+    pub fn is_passage_blocked(&self, from: (i32, i32), to: (i32, i32)) -> bool {
+        self.passage_to_grid(from, to)
+            .is_some_and(|(x, y)| self.is_wall(x, y))
+    }
+
+    /// Enregistre un mur entre deux cellules adjacentes (par exemple après un déplacement refusé).
+    pub fn block_passage(&mut self, from: (i32, i32), to: (i32, i32)) {
+        if let Some((x, y)) = self.passage_to_grid(from, to) {
+            self.set_wall(x, y);
+        }
+    }
+
+    /// Recopie dans cette carte les informations connues de `other` qui y sont encore inconnues.
+    ///
+    /// La cellule `c` de cette carte correspond à la cellule `c + offset` de `other`. Les informations
+    /// déjà présentes ici ne sont jamais écrasées ; les informations de parcours (`CellInfo`) restent propres
+    /// à chaque carte.
+    ///
+    /// # Exemples
+    ///
+    /// ```
+    /// use maze_engine::global_map::GlobalMap;
+    ///
+    /// let mut team = GlobalMap::new(10, 10);
+    /// team.update_from_radar(&[[' '; 7]; 7], (2, 0));
+    /// let mut local = GlobalMap::new(10, 10);
+    /// local.merge_from(&team, (2, 0));
+    /// assert!(local.is_passage_open((0, 0), (1, 0)));
+    /// ```
+    pub fn merge_from(&mut self, other: &GlobalMap, offset: (i32, i32)) {
+        for (row, line) in other.grid.iter().enumerate() {
+            for (column, &knowledge) in line.iter().enumerate() {
+                if knowledge == Knowledge::Unknown {
+                    continue;
+                }
+                let (x, y) = (other.grid_min.0 + column as i32, other.grid_min.1 + row as i32);
+                let target_x = x - other.origin.0 - 2 * offset.0 + self.origin.0;
+                let target_y = y - other.origin.1 - 2 * offset.1 + self.origin.1;
+                if !self.is_visited(target_x, target_y) {
+                    self.write(target_x, target_y, knowledge);
+                }
+            }
+        }
+        if let (None, Some((goal_x, goal_y))) = (self.goal, other.goal) {
+            self.set_goal((goal_x - offset.0, goal_y - offset.1));
         }
     }
 
+    /// Écrit l'état d'une case, en agrandissant la grille si nécessaire.
+    ///
+    /// L'écriture est ignorée si la case est hors des limites connues du labyrinthe.
+    fn write(&mut self, x: i32, y: i32, knowledge: Knowledge) {
+        let cell = self.grid_to_cell(x, y);
+        if let Some(limits) = self.cell_limits() {
+            // Les murs extérieurs se trouvent à une demi-cellule des cellules du bord.
+            let (min_x, min_y) = self.cell_to_grid((limits.min_x, limits.min_y));
+            let (max_x, max_y) = self.cell_to_grid((limits.max_x, limits.max_y));
+            if x < min_x - 1 || x > max_x + 1 || y < min_y - 1 || y > max_y + 1 {
+                return;
+            }
+        }
+        self.ensure_contains(x, y);
+        let (column, row) = ((x - self.grid_min.0) as usize, (y - self.grid_min.1) as usize);
+        self.grid[row][column] = knowledge;
+        if let Some(cell) = cell {
+            match &mut self.cell_bounds {
+                Some(bounds) => bounds.include(cell),
+                None => self.cell_bounds = Some(CellBounds::point(cell)),
+            }
+        }
+    }
+
+    /// Agrandit la grille pour qu'elle contienne la case `(x, y)`.
+    ///
+    /// La grille est au moins doublée dans la direction concernée, afin d'amortir le coût des agrandissements.
+    fn ensure_contains(&mut self, x: i32, y: i32) {
+        let width = self.grid[0].len() as i32;
+        if x < self.grid_min.0 {
+            let extra = (self.grid_min.0 - x).max(width) as usize;
+            for row in &mut self.grid {
+                row.splice(0..0, repeat_n(Knowledge::Unknown, extra));
+            }
+            self.grid_min.0 -= extra as i32;
+        } else if x >= self.grid_min.0 + width {
+            let extra = (x - self.grid_min.0 - width + 1).max(width) as usize;
+            for row in &mut self.grid {
+                row.extend(repeat_n(Knowledge::Unknown, extra));
+            }
+        }
+
+        let (width, height) = (self.grid[0].len(), self.grid.len() as i32);
+        if y < self.grid_min.1 {
+            let extra = (self.grid_min.1 - y).max(height) as usize;
+            self.grid.splice(0..0, repeat_n(vec![Knowledge::Unknown; width], extra));
+            self.grid_min.1 -= extra as i32;
+        } else if y >= self.grid_min.1 + height {
+            let extra = (y - self.grid_min.1 - height + 1).max(height) as usize;
+            self.grid.extend(repeat_n(vec![Knowledge::Unknown; width], extra));
+        }
+    }
+
+    /// Convertit une cellule (relative au départ) en coordonnées de grille.
+    fn cell_to_grid(&self, (x, y): (i32, i32)) -> (i32, i32) {
+        (self.origin.0 + 2 * x, self.origin.1 + 2 * y)
+    }
+
+    /// Convertit des coordonnées de grille en cellule, si elles désignent une cellule (et non un mur).
+    fn grid_to_cell(&self, x: i32, y: i32) -> Option<(i32, i32)> {
+        let (dx, dy) = (x - self.origin.0, y - self.origin.1);
+        (dx % 2 == 0 && dy % 2 == 0).then_some((dx / 2, dy / 2))
+    }
+
+    /// Retourne les coordonnées de grille du passage séparant deux cellules adjacentes.
+    fn passage_to_grid(&self, from: (i32, i32), to: (i32, i32)) -> Option<(i32, i32)> {
+        let (from_x, from_y) = self.cell_to_grid(from);
+        let (to_x, to_y) = self.cell_to_grid(to);
+        if (from_x - to_x).abs() + (from_y - to_y).abs() != 2 {
+            return None;
+        }
+        Some(((from_x + to_x) / 2, (from_y + to_y) / 2))
+    }
+
     /// Affiche la carte globale dans la console.
     ///
     /// Chaque ligne de la carte est affichée sous forme de chaîne de caractères.
     pub fn print_map(&self) {
         println!("Carte globale :");
-        for row in &self.grid {
-            println!("{}", row.iter().collect::<String>());
+        let width = self.grid[0].len() as i32;
+        for row in 0..self.grid.len() as i32 {
+            let line: String = (0..width)
+                .map(|column| self.get(self.grid_min.0 + column, self.grid_min.1 + row))
+                .collect();
+            println!("{}", line);
         }
     }
 }
 
+impl Default for GlobalMap {
+    fn default() -> Self {
+        Self::new(0, 0)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -117,7 +565,7 @@ mod tests {
     #[test]
     fn test_set_wall_and_is_wall() {
         let mut map = GlobalMap::new(0, 0);
-        map.set_wall(2, 3, '-');
+        map.set_wall(2, 3);
         assert!(map.is_wall(2, 3));
         assert!(map.is_visited(2, 3));
     }
@@ -125,17 +573,149 @@ mod tests {
     #[test]
     fn test_set_explored_and_is_visited() {
         let mut map = GlobalMap::new(0, 0);
-        map.set_explored(5, 5, ' ');
+        map.set_explored(5, 5);
         assert!(map.is_visited(5, 5));
         // La case ne doit pas être considérée comme mur
         assert!(!map.is_wall(5, 5));
     }
 
+    #[test]
+    fn test_update_from_radar() {
+        let mut radar_view = [[' '; 7]; 7];
+        radar_view[2][3] = '-'; // Mur au-dessus du joueur
+        radar_view[0][0] = '#'; // Zone non définie
+        let mut map = GlobalMap::new(10, 10);
+        map.update_from_radar(&radar_view, (0, 0));
+
+        assert!(map.is_cell_explored((0, 0)));
+        assert!(map.is_cell_explored((-1, 1)));
+        assert!(!map.is_cell_explored((2, 0)));
+        assert!(map.is_passage_blocked((0, 0), (0, -1)));
+        assert!(!map.is_passage_open((0, 0), (0, -1)));
+        assert!(map.is_passage_open((0, 0), (1, 0)));
+        // Le bord du radar est connu, mais pas la cellule qui se trouve au-delà.
+        assert!(map.is_passage_open((1, 0), (2, 0)));
+        // Un passage hors de la vue radar n'est ni ouvert ni bloqué.
+        assert!(!map.is_passage_open((2, 0), (3, 0)));
+        assert!(!map.is_passage_blocked((2, 0), (3, 0)));
+    }
+
+    #[test]
+    fn test_block_passage_and_bounds() {
+        let mut map = GlobalMap::new(10, 10);
+        map.update_from_radar(&[[' '; 7]; 7], (0, 0));
+        map.block_passage((0, 0), (1, 0));
+        assert!(map.is_passage_blocked((0, 0), (1, 0)));
+        // Sans indice de taille, la carte n'est pas bornée.
+        assert!(map.contains_cell((10, 0)));
+        assert!(!map.is_cell_explored((10, 0)));
+        assert!(!map.is_wall(-1000, 1000));
+        // Des cellules non adjacentes n'ont pas de passage.
+        assert!(!map.is_passage_open((0, 0), (2, 0)));
+    }
+
+    #[test]
+    fn test_set_goal() {
+        let mut map = GlobalMap::new(10, 10);
+        assert_eq!(map.goal(), None);
+        map.set_goal((1, 1));
+        assert_eq!(map.goal(), Some((1, 1)));
+        assert!(map.is_cell_explored((1, 1)));
+        assert!(!map.is_wall(12, 12));
+    }
+
+    #[test]
+    fn test_unknown_is_not_a_wall() {
+        let mut map = GlobalMap::new(0, 0);
+        let mut radar_view = [['#'; 7]; 7];
+        radar_view[3][3] = ' ';
+        radar_view[3][4] = '|';
+        map.update_from_radar(&radar_view, (0, 0));
+        assert_eq!(map.cell_state((0, 0)), Knowledge::Open);
+        assert_eq!(map.cell_state((1, 0)), Knowledge::Unknown);
+        assert_eq!(map.passage_state((0, 0), (1, 0)), Knowledge::Wall);
+        assert_eq!(map.passage_state((0, 0), (0, 1)), Knowledge::Unknown);
+        assert!(!map.is_passage_blocked((0, 0), (0, 1)));
+        assert_eq!(map.get(1, 0), '|');
+        assert_eq!(map.get(0, 1), '#');
+    }
+
+    #[test]
+    fn test_cell_info() {
+        let mut map = GlobalMap::new(0, 0);
+        assert_eq!(map.cell_info((0, 0)), CellInfo::default());
+        map.update_from_radar(&[[' '; 7]; 7], (0, 0));
+        map.record_visit((0, 0));
+        map.update_from_radar(&[[' '; 7]; 7], (1, 0));
+        map.record_visit((1, 0));
+        map.record_visit((0, 0));
+        map.record_hint((1, 0));
+        assert_eq!(map.tick(), 2);
+        assert_eq!(map.cell_info((0, 0)), CellInfo { visits: 2, last_seen: Some(2), hint_seen: false });
+        assert_eq!(map.cell_info((-1, 0)).last_seen, Some(1));
+        assert!(map.cell_info((1, 0)).hint_seen);
+    }
+
+    #[test]
+    fn test_map_grows_in_every_direction() {
+        let mut map = GlobalMap::new(0, 0);
+        for position in [(-30, 0), (30, 0), (0, -30), (0, 30)] {
+            map.update_from_radar(&[[' '; 7]; 7], position);
+        }
+        map.block_passage((-30, 0), (-31, 0));
+        assert!(map.is_cell_explored((-31, 1)));
+        assert!(map.is_cell_explored((31, -1)));
+        assert!(map.is_cell_explored((0, -31)));
+        assert!(map.is_cell_explored((1, 31)));
+        assert!(map.is_passage_blocked((-30, 0), (-31, 0)));
+        assert_eq!(
+            map.explored_bounds(),
+            Some(CellBounds { min_x: -31, max_x: 31, min_y: -31, max_y: 31 })
+        );
+    }
+
+    #[test]
+    fn test_grid_size_clips_map() {
+        let mut map = GlobalMap::new(0, 0);
+        map.update_from_radar(&[[' '; 7]; 7], (0, 0));
+        map.update_from_radar(&[[' '; 7]; 7], (-2, 0));
+        // Les cellules explorées vont de -3 à 1 : un labyrinthe de 6 colonnes va au plus de -4 à 2.
+        map.set_grid_size(6, 3);
+        assert_eq!(
+            map.cell_limits(),
+            Some(CellBounds { min_x: -4, max_x: 2, min_y: -1, max_y: 1 })
+        );
+        assert!(map.contains_cell((2, 0)));
+        assert!(!map.contains_cell((3, 0)));
+        assert!(!map.contains_cell((0, 2)));
+        // Les écritures hors des limites sont ignorées, mais pas les murs extérieurs.
+        map.set_explored(10, 0);
+        assert!(!map.is_visited(10, 0));
+        map.block_passage((0, 1), (0, 2));
+        assert!(map.is_passage_blocked((0, 1), (0, 2)));
+    }
+
+    #[test]
+    fn test_merge_from_keeps_known_information() {
+        let mut team = GlobalMap::new(10, 10);
+        team.update_from_radar(&[[' '; 7]; 7], (0, 0));
+        team.set_goal((1, 1));
+        let mut local = GlobalMap::new(10, 10);
+        let mut radar_view = [['#'; 7]; 7];
+        radar_view[3][4] = '|';
+        local.update_from_radar(&radar_view, (-1, 0));
+        // La cellule locale (-1, 0) correspond à la cellule (0, 0) de l'équipe.
+        local.merge_from(&team, (1, 0));
+        assert!(local.is_passage_blocked((-1, 0), (0, 0)));
+        assert!(local.is_passage_open((-1, 0), (-1, 1)));
+        assert_eq!(local.goal(), Some((0, 1)));
+    }
+
     #[test]
     fn test_print_map() {
         let mut map = GlobalMap::new(0, 0);
-        map.set_explored(1, 1, ' ');
-        map.set_wall(2, 2, '-');
+        map.set_explored(1, 1);
+        map.set_wall(2, 2);
         map.print_map();
     }
 }
@@ -3,6 +3,11 @@ pub mod challenge;
 pub mod radar;
 pub mod navigation;
 pub mod global_map;
+pub mod pathfinding;
+pub mod export;
+pub mod strategy;
+pub mod team_map;
+pub mod log;
 
 pub use commun::structs::ActionError;
 pub use commun::structs::RelativeDirection;
@@ -0,0 +1,34 @@
+use std::sync::atomic::{AtomicBool, Ordering};
+
+/// Journaux écrits sur la sortie standard (voir [`log!`](crate::log!)).
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+/// Active ou coupe les journaux, par exemple le temps d'afficher une interface plein écran.
+///
+/// # Exemple
+///
+/// ```
+/// use maze_engine::log;
+///
+/// log::set_enabled(false);
+/// assert!(!log::is_enabled());
+/// log::set_enabled(true);
+/// ```
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+/// Indique si les journaux sont écrits.
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+/// Écrit une ligne de journal sur la sortie standard, comme `println!`, sauf si les journaux sont coupés.
+#[macro_export]
+macro_rules! log {
+    ($($arg:tt)*) => {
+        if $crate::log::is_enabled() {
+            println!($($arg)*);
+        }
+    };
+}
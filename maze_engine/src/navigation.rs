@@ -1,14 +1,34 @@
 use commun::structs::RelativeDirection;
 use std::collections::{HashMap, HashSet, VecDeque};
 use rand::distributions::Distribution;
+use crate::global_map::GlobalMap;
+use crate::pathfinding::find_path;
+use crate::strategy::{NavigationContext, NavigationStrategy};
+use crate::team_map::TeamMember;
 
-const MAX_FAILS: usize = 3; // Nombre maximum d'échecs avant de bannir une direction temporairement
+pub use crate::strategy::ExplorationStrategy;
+
+pub(crate) const MAX_FAILS: usize = 3; // Nombre maximum d'échecs avant de bannir une direction temporairement
+/// Poids par défaut de l'indice boussole dans le classement des frontières (en nombre de déplacements).
+pub const DEFAULT_COMPASS_WEIGHT: f32 = 2.0;
+
+/// Mode de déplacement courant du `Navigator`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum NavigationMode {
+    /// Exploration du labyrinthe selon la stratégie choisie.
+    Exploring,
+    /// La sortie est connue et atteignable : le `Navigator` suit le plus court chemin vers elle.
+    SeekingGoal,
+    /// Le joueur est chargé de secourir un coéquipier en détresse et le rejoint.
+    Rescuing,
+}
 
 /// Gère la navigation dans le labyrinthe en mémorisant l'historique des déplacements, les positions visitées,
 /// et en déterminant le prochain mouvement à effectuer en fonction de la vue radar.
 ///
 /// La structure `Navigator` s'appuie sur plusieurs champs internes pour éviter de revenir sur ses pas
 /// lorsque d'autres options sont disponibles, et pour gérer les échecs de déplacement.
+/// Le choix des déplacements d'exploration est délégué à une [`NavigationStrategy`].
 pub struct Navigator {
     pub last_direction: Option<RelativeDirection>,
     /// Historique des déplacements effectués.
@@ -21,10 +41,26 @@ pub struct Navigator {
     fail_count: HashMap<RelativeDirection, usize>,
     /// Ensemble des directions temporairement bannies.
     banned_directions: HashSet<RelativeDirection>,
+    /// Carte globale construite à partir des vues radar successives.
+    pub global_map: GlobalMap,
+    /// Stratégie utilisée pour choisir le prochain déplacement.
+    strategy: Box<dyn NavigationStrategy>,
+    /// Mode de déplacement utilisé lors du dernier choix.
+    mode: NavigationMode,
+    /// Direction absolue de la sortie (en degrés, sens horaire depuis `Front`) d'après le dernier indice boussole.
+    exit_bearing: Option<f32>,
+    /// Poids de l'indice boussole dans le classement des frontières.
+    compass_weight: f32,
+    /// Accès à la carte partagée de l'équipe, si le joueur joue en équipe.
+    team: Option<TeamMember>,
+    /// Nombre de coéquipiers secourus.
+    rescues_performed: u32,
+    /// Déplacement dépilé de l'historique par le dernier demi-tour envoyé, tant que le serveur ne l'a pas accepté.
+    undone_move: Option<RelativeDirection>,
 }
 
 impl Navigator {
-    /// Crée un nouveau `Navigator` avec un état initial vide.
+    /// Crée un nouveau `Navigator` avec un état initial vide, utilisant l'exploration par frontières.
     ///
     /// # Exemple
     ///
@@ -34,6 +70,24 @@ impl Navigator {
     /// assert_eq!(navigator.current_position, (0, 0));
     /// ```
     pub fn new() -> Self {
+        Self::with_strategy(ExplorationStrategy::Frontier)
+    }
+
+    /// Crée un nouveau `Navigator` utilisant la stratégie donnée.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use maze_engine::navigation::{ExplorationStrategy, Navigator};
+    /// let navigator = Navigator::with_strategy(ExplorationStrategy::Greedy);
+    /// assert_eq!(navigator.strategy_name(), "greedy");
+    /// ```
+    pub fn with_strategy(strategy: ExplorationStrategy) -> Self {
+        Self::with_navigation_strategy(strategy.build())
+    }
+
+    /// Crée un nouveau `Navigator` utilisant une implémentation quelconque de [`NavigationStrategy`].
+    pub fn with_navigation_strategy(strategy: Box<dyn NavigationStrategy>) -> Self {
         Self {
             last_direction: None,
             movement_history: VecDeque::new(),
@@ -41,13 +95,120 @@ impl Navigator {
             current_position: (0, 0),
             fail_count: HashMap::new(),
             banned_directions: HashSet::new(),
+            global_map: GlobalMap::default(),
+            strategy,
+            mode: NavigationMode::Exploring,
+            exit_bearing: None,
+            compass_weight: DEFAULT_COMPASS_WEIGHT,
+            team: None,
+            rescues_performed: 0,
+            undone_move: None,
+        }
+    }
+
+    /// Relie le `Navigator` à la carte partagée de son équipe.
+    ///
+    /// Les vues radar, les murs découverts et la sortie sont alors partagés avec les coéquipiers, dont
+    /// les découvertes sont recopiées dans la carte locale ; les frontières visées par les coéquipiers
+    /// sont évitées par les stratégies qui en tiennent compte.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use maze_engine::navigation::Navigator;
+    /// use maze_engine::team_map::TeamMap;
+    ///
+    /// let team = TeamMap::new();
+    /// let mut navigator = Navigator::new();
+    /// navigator.join_team(team.join("player1"));
+    /// navigator.choose_next_move(&[[' '; 7]; 7]);
+    /// ```
+    pub fn join_team(&mut self, member: TeamMember) {
+        self.team = Some(member);
+    }
+
+    /// Retourne l'accès à la carte de l'équipe, si le joueur joue en équipe.
+    pub fn team(&self) -> Option<&TeamMember> {
+        self.team.as_ref()
+    }
+
+    /// Retourne le nom de la stratégie d'exploration utilisée.
+    pub fn strategy_name(&self) -> &'static str {
+        self.strategy.name()
+    }
+
+    /// Retourne le mode de déplacement utilisé lors du dernier choix.
+    pub fn mode(&self) -> NavigationMode {
+        self.mode
+    }
+
+    /// Définit le poids de l'indice boussole dans le classement des frontières.
+    ///
+    /// Un poids nul revient à viser la frontière la plus proche. Avec un poids `w`, une frontière
+    /// parfaitement alignée avec la sortie est préférée à une frontière non alignée plus proche
+    /// d'au plus `w` déplacements.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use maze_engine::navigation::Navigator;
+    /// let mut navigator = Navigator::new();
+    /// navigator.set_compass_weight(0.0);
+    /// assert_eq!(navigator.compass_weight(), 0.0);
+    /// ```
+    pub fn set_compass_weight(&mut self, weight: f32) {
+        self.compass_weight = weight.max(0.0);
+    }
+
+    /// Retourne le poids de l'indice boussole.
+    pub fn compass_weight(&self) -> f32 {
+        self.compass_weight
+    }
+
+    /// Enregistre un indice `RelativeCompass`.
+    ///
+    /// Le repère du `Navigator` ne tourne jamais (`Front` est toujours vers le haut) : l'angle reçu est
+    /// interprété dans ce même repère absolu, quelle que soit la dernière direction de déplacement.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use maze_engine::navigation::Navigator;
+    /// let mut navigator = Navigator::new();
+    /// navigator.last_direction = Some(commun::structs::RelativeDirection::Right);
+    /// navigator.set_compass_hint(90.0);
+    /// assert_eq!(navigator.exit_bearing(), Some(90.0));
+    /// ```
+    pub fn set_compass_hint(&mut self, angle: f32) {
+        let bearing = Self::normalize_angle(angle);
+        crate::log!("[Navigator] Compass hint {}° -> exit bearing {}°", angle, bearing);
+        self.exit_bearing = Some(bearing);
+    }
+
+    /// Enregistre la réception d'un indice sur la position actuelle.
+    pub fn record_hint(&mut self) {
+        self.global_map.record_hint(self.current_position);
+    }
+
+    /// Enregistre la taille du labyrinthe reçue via l'indice `GridSize` (voir [`GlobalMap::set_grid_size`]).
+    pub fn set_grid_size(&mut self, columns: u32, rows: u32) {
+        self.global_map.set_grid_size(columns, rows);
+        if let Some(team) = &self.team {
+            team.set_grid_size(columns, rows);
         }
     }
 
+    /// Retourne la direction absolue estimée de la sortie, si un indice boussole a été reçu.
+    pub fn exit_bearing(&self) -> Option<f32> {
+        self.exit_bearing
+    }
+
     /// Choisit le prochain déplacement en fonction de la vue radar.
     ///
-    /// L'algorithme privilégie les cases non visitées et évite de revenir sur ses pas (demi-tour)
-    /// si une autre option est disponible.
+    /// La vue radar est d'abord intégrée à la carte globale. Si la sortie est connue et atteignable,
+    /// le `Navigator` passe en mode [`NavigationMode::SeekingGoal`] et s'y dirige directement ;
+    /// sinon le déplacement est choisi par sa stratégie (voir [`ExplorationStrategy`]). Si la stratégie
+    /// n'a aucune proposition, le `Navigator` effectue un demi-tour.
     ///
     /// # Arguments
     ///
@@ -65,62 +226,204 @@ impl Navigator {
     /// let radar_view = [[' '; 7]; 7];
     /// let mut navigator = Navigator::new();
     /// let next_move = navigator.choose_next_move(&radar_view);
-    /// // La direction retournée dépend de la vue ; ici, puisque toutes les cases sont "ouvertes" et non visitées,
-    /// // c'est la première option de la liste qui sera choisie.
+    /// // La direction retournée dépend de la vue ; ici, puisque toutes les cases sont "ouvertes",
+    /// // la frontière la plus proche est atteinte par la première direction explorée (Front).
     /// ```
     pub fn choose_next_move(&mut self, radar_view: &[[char; 7]; 7]) -> RelativeDirection {
-        println!("[Navigator] Current position: {:?}", self.current_position);
-        println!("[Navigator] Radar View:");
+        crate::log!("[Navigator] Current position: {:?}", self.current_position);
+        crate::log!("[Navigator] Radar View:");
         for row in radar_view.iter() {
-            println!("{}", row.iter().collect::<String>());
+            crate::log!("{}", row.iter().collect::<String>());
         }
 
-        // Démarrer avec toutes les directions possibles
-        let mut possible_moves = vec![
-            RelativeDirection::Front,
-            RelativeDirection::Right,
-            RelativeDirection::Left,
-            RelativeDirection::Back,
-        ];
-
-        // Filtrer selon les directions bannies, celles ayant trop d'échecs ou bloquées d'après la vue radar.
-        possible_moves.retain(|&dir| {
-            let fails = self.fail_count.get(&dir).cloned().unwrap_or(0);
-            fails < MAX_FAILS && !self.banned_directions.contains(&dir) && self.is_open(radar_view, dir)
-        });
-
-        // Si plusieurs options sont disponibles, éviter le demi-tour (la direction opposée à la dernière)
-        if possible_moves.len() > 1 {
-            if let Some(last_dir) = self.last_direction {
-                let reverse_dir = Self::turn_back(last_dir);
-                if possible_moves.len() > 1 && possible_moves.contains(&reverse_dir) {
-                    possible_moves.retain(|&dir| dir != reverse_dir);
+        self.observe(radar_view);
+        let reserved_cells = match &self.team {
+            Some(team) => team.reserved_by_teammates(),
+            None => HashSet::new(),
+        };
+
+        if let Some(direction) = self.choose_rescue_move() {
+            self.mode = NavigationMode::Rescuing;
+            return direction;
+        }
+        if let Some(direction) = self.choose_goal_move() {
+            self.mode = NavigationMode::SeekingGoal;
+            return direction;
+        }
+        self.mode = NavigationMode::Exploring;
+
+        let context = NavigationContext {
+            radar_view,
+            position: self.current_position,
+            last_direction: self.last_direction,
+            movement_history: &self.movement_history,
+            visited_positions: &self.visited_positions,
+            fail_count: &self.fail_count,
+            banned_directions: &self.banned_directions,
+            global_map: &self.global_map,
+            exit_bearing: self.exit_bearing,
+            compass_weight: self.compass_weight,
+            reserved_cells: &reserved_cells,
+        };
+        match self.strategy.next_move(&context) {
+            Some(direction) => {
+                if let (Some(team), Some(target)) = (&self.team, self.strategy.target()) {
+                    team.reserve(target);
+                }
+                self.apply_move(direction);
+                direction
+            }
+            None => {
+                let backtracking = !self.movement_history.is_empty();
+                let direction = self.handle_no_moves();
+                self.apply_move(direction);
+                if backtracking {
+                    // Un demi-tour dépile l'historique des déplacements au lieu de l'allonger ;
+                    // le déplacement dépilé est rétabli si le serveur refuse le demi-tour.
+                    self.movement_history.pop_back();
+                    self.undone_move = Some(Self::turn_back(direction));
                 }
+                direction
             }
         }
+    }
 
-        // Préférer une direction qui mène vers une case non visitée
-        for &dir in &possible_moves {
-            let new_pos = Self::calculate_new_position(self.current_position, dir);
-            if !self.visited_positions.contains(&new_pos) {
-                self.execute_move(dir, new_pos);
-                self.last_direction = Some(dir);
-                return dir;
+    /// Intègre une vue radar à la carte globale (et à celle de l'équipe) sans choisir de déplacement.
+    ///
+    /// Utilisé par [`Navigator::choose_next_move`], ou directement lorsque les déplacements sont
+    /// choisis ailleurs (par exemple au clavier), puis enregistrés avec [`Navigator::record_move`].
+    pub fn observe(&mut self, radar_view: &[[char; 7]; 7]) {
+        self.global_map.update_from_radar(radar_view, self.current_position);
+        self.global_map.record_visit(self.current_position);
+        let goal = Self::locate_goal(radar_view, self.current_position);
+        if let Some(goal) = goal {
+            self.global_map.set_goal(goal);
+        }
+        if let Some(team) = &self.team {
+            team.observe(radar_view, self.current_position);
+            if let Some(goal) = goal {
+                team.report_goal(goal);
             }
+            team.sync(&mut self.global_map);
         }
+    }
+
+    /// Enregistre un déplacement choisi hors du `Navigator` (par exemple par un joueur humain).
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use maze_engine::navigation::Navigator;
+    /// use commun::structs::RelativeDirection;
+    ///
+    /// let mut navigator = Navigator::new();
+    /// navigator.observe(&[[' '; 7]; 7]);
+    /// navigator.record_move(RelativeDirection::Left);
+    /// assert_eq!(navigator.current_position, (-1, 0));
+    /// assert_eq!(navigator.last_direction, Some(RelativeDirection::Left));
+    /// ```
+    pub fn record_move(&mut self, direction: RelativeDirection) {
+        self.apply_move(direction);
+    }
 
-        // Si toutes les options mènent vers des cases déjà visitées, choisir celle avec le moins d'échecs
-        if !possible_moves.is_empty() {
-            possible_moves.sort_by_key(|&dir| self.fail_count.get(&dir).cloned().unwrap_or(0));
-            let chosen = possible_moves[0];
-            let new_pos = Self::calculate_new_position(self.current_position, chosen);
-            self.execute_move(chosen, new_pos);
-            self.last_direction = Some(chosen);
-            return chosen;
+    /// Recherche la sortie (`G`) parmi les neuf cellules de la vue radar.
+    ///
+    /// # Arguments
+    ///
+    /// * `radar_view` - La matrice 7x7 de la vue radar.
+    /// * `position` - La position du joueur, au centre de la vue.
+    ///
+    /// # Retourne
+    ///
+    /// La position de la sortie dans le repère du `Navigator`, si elle est visible.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use maze_engine::navigation::Navigator;
+    /// let mut radar_view = [[' '; 7]; 7];
+    /// radar_view[1][5] = 'G';
+    /// assert_eq!(Navigator::locate_goal(&radar_view, (2, 2)), Some((3, 1)));
+    /// ```
+    pub fn locate_goal(radar_view: &[[char; 7]; 7], (x, y): (i32, i32)) -> Option<(i32, i32)> {
+        for row in [1, 3, 5] {
+            for column in [1, 3, 5] {
+                if radar_view[row][column] == 'G' {
+                    return Some((x + (column as i32 - 3) / 2, y + (row as i32 - 3) / 2));
+                }
+            }
         }
+        None
+    }
+
+    /// Avance d'un pas vers le coéquipier que le joueur est chargé de secourir.
+    ///
+    /// Le secours est terminé dès que le joueur atteint la cellule du coéquipier.
+    ///
+    /// # Retourne
+    ///
+    /// `None` si le joueur n'a personne à secourir, ou si aucun chemin connu ne mène au coéquipier
+    /// (l'exploration reprend alors pour en découvrir un).
+    fn choose_rescue_move(&mut self) -> Option<RelativeDirection> {
+        let team = self.team.as_ref()?;
+        let target = team.rescue_target()?;
+        if target == self.current_position {
+            if team.complete_rescue().is_some() {
+                self.rescues_performed += 1;
+            }
+            return None;
+        }
+        let path = find_path(&self.global_map, self.current_position, |cell| cell == target)?;
+        let direction = Self::direction_between(self.current_position, *path.first()?)?;
+        crate::log!("[Navigator] Rescuing teammate at {:?}, moving {:?}", target, direction);
+        self.apply_move(direction);
+        Some(direction)
+    }
+
+    /// Diffuse la position du joueur à ses coéquipiers pour qu'ils viennent le secourir (défi `SOS`).
+    ///
+    /// # Retourne
+    ///
+    /// `false` si le joueur ne joue pas en équipe ou n'a pas encore de repère commun avec ses coéquipiers.
+    pub fn request_rescue(&self) -> bool {
+        self.team
+            .as_ref()
+            .is_some_and(|team| team.request_rescue(self.current_position))
+    }
+
+    /// Retourne le nombre de coéquipiers secourus par le joueur.
+    pub fn rescues_performed(&self) -> u32 {
+        self.rescues_performed
+    }
+
+    /// Indique si le joueur attend encore d'être secouru.
+    pub fn awaiting_rescue(&self) -> bool {
+        self.team.as_ref().is_some_and(|team| team.awaiting_rescue())
+    }
+
+    /// Avance d'un pas sur le plus court chemin connu vers la sortie.
+    ///
+    /// # Retourne
+    ///
+    /// `None` si la sortie est inconnue, inatteignable par des passages connus, ou déjà atteinte.
+    fn choose_goal_move(&mut self) -> Option<RelativeDirection> {
+        let goal = self.global_map.goal()?;
+        let path = find_path(&self.global_map, self.current_position, |cell| cell == goal)?;
+        let next_position = *path.first()?;
+        let direction = Self::direction_between(self.current_position, next_position)?;
+        crate::log!("[Navigator] Goal at {:?}, moving {:?}", goal, direction);
+        self.apply_move(direction);
+        Some(direction)
+    }
 
-        // Sinon, si aucune option n'est possible, effectuer un demi-tour.
-        self.handle_no_moves()
+    /// Enregistre un déplacement choisi : la stratégie en est notifiée, puis l'état interne est mis à jour.
+    fn apply_move(&mut self, direction: RelativeDirection) {
+        // Un nouveau déplacement n'est envoyé qu'une fois le précédent accepté.
+        self.undone_move = None;
+        let new_pos = Self::calculate_new_position(self.current_position, direction);
+        self.strategy.on_move(self.current_position, new_pos);
+        self.execute_move(direction, new_pos);
+        self.last_direction = Some(direction);
     }
 
     /// Met à jour l'état interne du Navigator en enregistrant le déplacement effectué.
@@ -136,7 +439,7 @@ impl Navigator {
     /// * `direction` - La direction dans laquelle se déplacer.
     /// * `new_pos` - La nouvelle position calculée après déplacement.
     pub fn execute_move(&mut self, direction: RelativeDirection, new_pos: (i32, i32)) {
-        println!("[Navigator] Executing move {:?} to {:?}", direction, new_pos);
+        crate::log!("[Navigator] Executing move {:?} to {:?}", direction, new_pos);
         self.banned_directions.remove(&direction);
         self.fail_count.remove(&direction);
         self.movement_history.push_back(direction);
@@ -150,7 +453,7 @@ impl Navigator {
     /// - Au-delà de 135° (ou en dessous de -135°) -> Back
     /// - Entre -135° et -45° -> Left
     pub fn compute_direction_from_angle(angle: f32) -> Option<RelativeDirection> {
-        if angle >= -45.0 && angle <= 45.0 {
+        if (-45.0..=45.0).contains(&angle) {
             Some(RelativeDirection::Front)
         } else if angle > 45.0 && angle < 135.0 {
             Some(RelativeDirection::Right)
@@ -163,6 +466,16 @@ impl Navigator {
         }
     }
 
+    /// Ramène un angle (en degrés) dans l'intervalle `]-180, 180]`.
+    fn normalize_angle(angle: f32) -> f32 {
+        let angle = angle.rem_euclid(360.0);
+        if angle > 180.0 {
+            angle - 360.0
+        } else {
+            angle
+        }
+    }
+
     /// Gère le cas où aucun déplacement valide n'est possible en effectuant un demi-tour.
     ///
     /// Si l'historique des mouvements n'est pas vide, la fonction effectue un retour (demi-tour) à partir
@@ -174,7 +487,7 @@ impl Navigator {
     /// La direction choisie pour le demi-tour ou aléatoirement.
     fn handle_no_moves(&mut self) -> RelativeDirection {
         if let Some(last_move) = self.movement_history.pop_back() {
-            println!("[Navigator] No valid moves, turning back from {:?}", last_move);
+            crate::log!("[Navigator] No valid moves, turning back from {:?}", last_move);
             self.banned_directions.insert(last_move);
             *self.fail_count.entry(last_move).or_insert(0) += 1;
             return Self::turn_back(last_move);
@@ -182,7 +495,7 @@ impl Navigator {
         use rand::distributions::Standard;
         let mut rng = rand::thread_rng();
         let random_direction: RelativeDirection = Standard.sample(&mut rng);
-        println!(
+        crate::log!(
             "[Navigator] No moves and no history, choosing random direction: {:?}",
             random_direction
         );
@@ -199,19 +512,105 @@ impl Navigator {
     ///
     /// * `direction` - La direction dans laquelle le déplacement a échoué.
     pub fn handle_move_failure(&mut self, direction: RelativeDirection) {
-        println!("[Navigator] Move failed in direction {:?}, reverting move.", direction);
+        crate::log!("[Navigator] Move failed in direction {:?}, reverting move.", direction);
         let (dx, dy) = Self::delta(direction);
         let previous_position = (self.current_position.0 - dx, self.current_position.1 - dy);
-        println!("[Navigator] Reverting position from {:?} to {:?}", self.current_position, previous_position);
+        crate::log!("[Navigator] Reverting position from {:?} to {:?}", self.current_position, previous_position);
         self.current_position = previous_position;
-        if let Some(last_move) = self.movement_history.pop_back() {
-            println!("[Navigator] Removing last move {:?} due to failure", last_move);
+        let backtracking = self.restore_undone_move();
+        if !backtracking {
+            if let Some(last_move) = self.movement_history.pop_back() {
+                crate::log!("[Navigator] Removing last move {:?} due to failure", last_move);
+            }
         }
         self.banned_directions.insert(direction);
         *self.fail_count.entry(direction).or_insert(0) += 1;
-        // Supprimer la position incorrectement ajoutée.
+        // Supprimer la position incorrectement ajoutée (sauf après un demi-tour : elle avait déjà été visitée).
         let incorrect_position = (previous_position.0 + dx, previous_position.1 + dy);
-        self.visited_positions.remove(&incorrect_position);
+        if !backtracking {
+            self.visited_positions.remove(&incorrect_position);
+        }
+        // Mémoriser le mur sur la carte et forcer une nouvelle planification.
+        self.global_map.block_passage(previous_position, incorrect_position);
+        self.strategy.on_move_failure(previous_position, incorrect_position);
+        if let Some(team) = &self.team {
+            team.block_passage(previous_position, incorrect_position);
+        }
+    }
+
+    /// Annule le dernier déplacement, refusé par le serveur sans qu'un mur en soit la cause
+    /// (adversaire sur la cellule visée, défi à résoudre, joueur à secourir...).
+    ///
+    /// Contrairement à [`Navigator::handle_move_failure`], le passage n'est ni marqué comme bloqué
+    /// ni banni : il pourra être retenté.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use maze_engine::navigation::Navigator;
+    /// use commun::structs::RelativeDirection;
+    ///
+    /// let mut navigator = Navigator::new();
+    /// navigator.record_move(RelativeDirection::Right);
+    /// navigator.cancel_move();
+    /// assert_eq!(navigator.current_position, (0, 0));
+    /// assert!(!navigator.global_map.is_passage_blocked((0, 0), (1, 0)));
+    /// ```
+    pub fn cancel_move(&mut self) {
+        let (direction, backtracking) = match self.undone_move {
+            Some(undone) => (Self::turn_back(undone), self.restore_undone_move()),
+            None => match self.movement_history.pop_back() {
+                Some(direction) => (direction, false),
+                None => {
+                    crate::log!("[Navigator] No recorded move to cancel.");
+                    return;
+                }
+            },
+        };
+        let rejected_position = self.current_position;
+        let (dx, dy) = Self::delta(direction);
+        self.current_position = (rejected_position.0 - dx, rejected_position.1 - dy);
+        crate::log!("[Navigator] Cancelling move {:?}, back to {:?}", direction, self.current_position);
+        if !backtracking {
+            self.visited_positions.remove(&rejected_position);
+        }
+        self.strategy.on_move_failure(self.current_position, rejected_position);
+    }
+
+    /// Remet dans l'historique le déplacement dépilé par un demi-tour refusé par le serveur.
+    ///
+    /// Retourne `true` si le déplacement refusé était un tel demi-tour.
+    fn restore_undone_move(&mut self) -> bool {
+        match self.undone_move.take() {
+            Some(undone) => {
+                crate::log!("[Navigator] Turning back refused, restoring move {:?}", undone);
+                self.movement_history.push_back(undone);
+                true
+            }
+            None => false,
+        }
+    }
+
+    /// Retourne le chemin parcouru, reconstitué depuis le départ `(0, 0)` à partir de l'historique des déplacements.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use maze_engine::navigation::Navigator;
+    /// use commun::structs::RelativeDirection;
+    ///
+    /// let mut navigator = Navigator::new();
+    /// navigator.execute_move(RelativeDirection::Right, (1, 0));
+    /// assert_eq!(navigator.path(), vec![(0, 0), (1, 0)]);
+    /// ```
+    pub fn path(&self) -> Vec<(i32, i32)> {
+        let mut position = (0, 0);
+        let mut path = vec![position];
+        for &direction in &self.movement_history {
+            position = Self::calculate_new_position(position, direction);
+            path.push(position);
+        }
+        path
     }
 
     /// Affiche l'état interne du Navigator, notamment les positions visitées et l'historique des déplacements.
@@ -282,6 +681,22 @@ impl Navigator {
         }
     }
 
+    /// Retourne la direction permettant de passer d'une cellule à une cellule adjacente.
+    ///
+    /// # Retourne
+    ///
+    /// `None` si les deux cellules ne sont pas adjacentes.
+    pub(crate) fn direction_between(from: (i32, i32), to: (i32, i32)) -> Option<RelativeDirection> {
+        [
+            RelativeDirection::Front,
+            RelativeDirection::Right,
+            RelativeDirection::Back,
+            RelativeDirection::Left,
+        ]
+        .into_iter()
+        .find(|&direction| Self::calculate_new_position(from, direction) == to)
+    }
+
     /// Retourne la direction opposée (demi-tour) à la direction donnée.
     ///
     /// # Arguments
@@ -291,7 +706,7 @@ impl Navigator {
     /// # Retourne
     ///
     /// La direction opposée.
-    fn turn_back(direction: RelativeDirection) -> RelativeDirection {
+    pub(crate) fn turn_back(direction: RelativeDirection) -> RelativeDirection {
         match direction {
             RelativeDirection::Front => RelativeDirection::Back,
             RelativeDirection::Back  => RelativeDirection::Front,
@@ -301,9 +716,17 @@ impl Navigator {
     }
 }
 
+impl Default for Navigator {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::global_map::Knowledge;
+    use crate::team_map::{TeamMap, TeamMember};
     use commun::structs::RelativeDirection;
 
     #[test]
@@ -335,6 +758,47 @@ mod tests {
         assert_eq!(result, RelativeDirection::Back);
     }
 
+    #[test]
+    fn test_no_moves_fallback_updates_position() {
+        // Cellule entièrement murée : aucune stratégie ne propose de déplacement.
+        let mut radar_view = [[' '; 7]; 7];
+        radar_view[2][3] = '-';
+        radar_view[4][3] = '-';
+        radar_view[3][2] = '|';
+        radar_view[3][4] = '|';
+        let mut navigator = Navigator::new();
+        navigator.execute_move(RelativeDirection::Front, (0, -1));
+        assert_eq!(navigator.choose_next_move(&radar_view), RelativeDirection::Back);
+        assert_eq!(navigator.current_position, (0, 0));
+        assert_eq!(navigator.last_direction, Some(RelativeDirection::Back));
+        assert!(navigator.movement_history.is_empty());
+    }
+
+    #[test]
+    fn test_cancelled_fallback_restores_history() {
+        // Le demi-tour envoyé faute de déplacement possible est refusé par le serveur.
+        let mut radar_view = [[' '; 7]; 7];
+        radar_view[2][3] = '-';
+        radar_view[4][3] = '-';
+        radar_view[3][2] = '|';
+        radar_view[3][4] = '|';
+        let mut navigator = Navigator::new();
+        navigator.execute_move(RelativeDirection::Right, (1, 0));
+        navigator.execute_move(RelativeDirection::Front, (1, -1));
+        assert_eq!(navigator.choose_next_move(&radar_view), RelativeDirection::Back);
+        navigator.cancel_move();
+        assert_eq!(navigator.current_position, (1, -1));
+        assert_eq!(navigator.movement_history, [RelativeDirection::Right, RelativeDirection::Front]);
+        assert!(navigator.visited_positions.contains(&(1, 0)));
+
+        // Même situation, mais le serveur signale un mur.
+        assert_eq!(navigator.choose_next_move(&radar_view), RelativeDirection::Back);
+        navigator.handle_move_failure(RelativeDirection::Back);
+        assert_eq!(navigator.current_position, (1, -1));
+        assert_eq!(navigator.movement_history, [RelativeDirection::Right, RelativeDirection::Front]);
+        assert!(navigator.visited_positions.contains(&(1, 0)));
+    }
+
     #[test]
     fn test_handle_move_failure() {
         // Teste la fonction handle_move_failure en simulant un échec de déplacement.
@@ -349,6 +813,203 @@ mod tests {
         assert!(navigator.banned_directions.contains(&RelativeDirection::Right));
     }
 
+    #[test]
+    fn test_frontier_follows_open_corridor() {
+        // Couloir vertical : murs à gauche et à droite du joueur, ouvert devant et derrière.
+        let mut radar_view = [[' '; 7]; 7];
+        for row in radar_view.iter_mut().take(6).skip(1) {
+            row[2] = '|';
+            row[4] = '|';
+        }
+        radar_view[6][3] = '-';
+        let mut navigator = Navigator::new();
+        assert_eq!(navigator.choose_next_move(&radar_view), RelativeDirection::Front);
+        assert_eq!(navigator.current_position, (0, -1));
+    }
+
+    #[test]
+    fn test_frontier_replans_after_wall_discovery() {
+        let mut navigator = Navigator::new();
+        assert_eq!(navigator.choose_next_move(&[[' '; 7]; 7]), RelativeDirection::Front);
+        // Le serveur refuse le déplacement : le mur est mémorisé et le chemin replanifié.
+        navigator.handle_move_failure(RelativeDirection::Front);
+        assert!(navigator.global_map.is_passage_blocked((0, 0), (0, -1)));
+        let mut radar_view = [[' '; 7]; 7];
+        radar_view[2][3] = '-';
+        assert_ne!(navigator.choose_next_move(&radar_view), RelativeDirection::Front);
+    }
+
+    #[test]
+    fn test_goal_seeking_overrides_exploration() {
+        // La sortie est visible en bas à droite ; le passage vers la droite est muré.
+        let mut radar_view = [[' '; 7]; 7];
+        radar_view[5][5] = 'G';
+        radar_view[3][4] = '|';
+        let mut navigator = Navigator::new();
+        assert_eq!(navigator.choose_next_move(&radar_view), RelativeDirection::Back);
+        assert_eq!(navigator.mode(), NavigationMode::SeekingGoal);
+        assert_eq!(navigator.global_map.goal(), Some((1, 1)));
+    }
+
+    #[test]
+    fn test_locate_goal_absent() {
+        assert_eq!(Navigator::locate_goal(&[[' '; 7]; 7], (0, 0)), None);
+        let navigator = Navigator::new();
+        assert_eq!(navigator.mode(), NavigationMode::Exploring);
+    }
+
+    #[test]
+    fn test_compass_ranks_aligned_frontier_first() {
+        // Sans indice, la frontière la plus proche est devant ; la boussole indique la droite.
+        let mut navigator = Navigator::new();
+        navigator.set_compass_hint(90.0);
+        assert_eq!(navigator.choose_next_move(&[[' '; 7]; 7]), RelativeDirection::Right);
+
+        // Avec un poids nul, l'indice est ignoré.
+        let mut navigator = Navigator::new();
+        navigator.set_compass_hint(90.0);
+        navigator.set_compass_weight(0.0);
+        assert_eq!(navigator.choose_next_move(&[[' '; 7]; 7]), RelativeDirection::Front);
+    }
+
+    #[test]
+    fn test_compass_hint_is_absolute() {
+        // La dernière direction de déplacement ne fait pas tourner l'indice.
+        let mut navigator = Navigator::new();
+        navigator.last_direction = Some(RelativeDirection::Left);
+        navigator.set_compass_hint(-135.0);
+        assert_eq!(navigator.exit_bearing(), Some(-135.0));
+        assert_eq!(Navigator::compute_direction_from_angle(-135.0), Some(RelativeDirection::Back));
+        navigator.set_compass_hint(270.0);
+        assert_eq!(navigator.exit_bearing(), Some(-90.0));
+    }
+
+    #[test]
+    fn test_nearest_teammate_goes_to_rescue() {
+        let team = TeamMap::new();
+        let mut alice = Navigator::new();
+        alice.join_team(team.join("alice"));
+        let mut bob = Navigator::new();
+        let member = team.join("bob");
+        member.report_meeting((0, 0), "alice", (0, 0));
+        bob.join_team(member);
+        let open = [[' '; 7]; 7];
+        bob.observe(&open);
+
+        alice.observe(&open);
+        alice.record_move(RelativeDirection::Right);
+        alice.observe(&open);
+        assert!(alice.request_rescue());
+        assert!(alice.awaiting_rescue());
+
+        // Bob rejoint Alice en (1, 0), puis signale la fin du secours.
+        assert_eq!(bob.choose_next_move(&open), RelativeDirection::Right);
+        assert_eq!(bob.mode(), NavigationMode::Rescuing);
+        bob.choose_next_move(&open);
+        assert_ne!(bob.mode(), NavigationMode::Rescuing);
+        assert!(!alice.awaiting_rescue());
+        assert_eq!(bob.rescues_performed(), 1);
+    }
+
+    #[test]
+    fn test_teammates_divide_frontiers() {
+        let team = TeamMap::new();
+        let mut alice = Navigator::new();
+        alice.join_team(team.join("alice"));
+        let mut bob = Navigator::new();
+        let member = team.join("bob");
+        member.report_meeting((0, 0), "alice", (0, 0));
+        bob.join_team(member);
+
+        // Alice vise la frontière devant elle ; Bob, au même endroit, en choisit une autre.
+        assert_eq!(alice.choose_next_move(&[[' '; 7]; 7]), RelativeDirection::Front);
+        assert_eq!(bob.choose_next_move(&[[' '; 7]; 7]), RelativeDirection::Right);
+    }
+
+    /// Labyrinthe 5x5 sans sortie visible, au format des vues radar.
+    const TEAM_MAZE: [&str; 11] = [
+        "•-•-•-•-•-•",
+        "|   |     |",
+        "• • • •-• •",
+        "| |   |   |",
+        "• •-•-• •-•",
+        "| |     | |",
+        "• • •-•-• •",
+        "|   |     |",
+        "•-• • •-•-•",
+        "|     |   |",
+        "•-•-•-•-•-•",
+    ];
+
+    /// Extrait la vue radar 7x7 centrée sur une cellule du labyrinthe de test.
+    fn radar_from_maze(maze: &[&str], (x, y): (i32, i32)) -> [[char; 7]; 7] {
+        let mut radar_view = [['#'; 7]; 7];
+        for (row, line) in radar_view.iter_mut().enumerate() {
+            for (column, content) in line.iter_mut().enumerate() {
+                let grid_x = 2 * x + column as i32 - 2;
+                let grid_y = 2 * y + row as i32 - 2;
+                if let Some(c) = maze.get(grid_y as usize).and_then(|l| l.chars().nth(grid_x as usize)) {
+                    *content = c;
+                }
+            }
+        }
+        radar_view
+    }
+
+    /// Caractère du passage entre deux cellules adjacentes du labyrinthe de test.
+    fn passage_in_maze(maze: &[&str], from: (i32, i32), to: (i32, i32)) -> char {
+        maze[(from.1 + to.1 + 1) as usize].chars().nth((from.0 + to.0 + 1) as usize).unwrap_or('#')
+    }
+
+    #[test]
+    fn test_teammates_anchor_on_shared_landmarks() {
+        let team = TeamMap::new();
+        let mut alice = Navigator::new();
+        alice.join_team(team.join("alice"));
+        let mut bob = Navigator::new();
+        bob.join_team(team.join("bob"));
+        let starts = [(0, 0), (4, 2)];
+        let mut positions = starts;
+
+        // Aucune rencontre n'est signalée : seules les vues radar permettent d'ancrer Bob.
+        for _ in 0..40 {
+            for (navigator, position) in [&mut alice, &mut bob].into_iter().zip(positions.iter_mut()) {
+                let direction = navigator.choose_next_move(&radar_from_maze(&TEAM_MAZE, *position));
+                let next = Navigator::calculate_new_position(*position, direction);
+                if passage_in_maze(&TEAM_MAZE, *position, next) == ' ' {
+                    *position = next;
+                } else {
+                    navigator.handle_move_failure(direction);
+                }
+            }
+            if bob.team().is_some_and(TeamMember::is_anchored) {
+                break;
+            }
+        }
+        assert!(bob.team().is_some_and(TeamMember::is_anchored));
+
+        // La carte de Bob, complétée par celle d'Alice, est cohérente avec le labyrinthe dans le repère de Bob.
+        bob.observe(&radar_from_maze(&TEAM_MAZE, positions[1]));
+        let (dx, dy) = starts[1];
+        for x in 0..5 {
+            for y in 0..5 {
+                for next in [(x + 1, y), (x, y + 1)] {
+                    let known = bob.global_map.passage_state((x - dx, y - dy), (next.0 - dx, next.1 - dy));
+                    let open = passage_in_maze(&TEAM_MAZE, (x, y), next) == ' ';
+                    assert!(known == Knowledge::Unknown || (known == Knowledge::Open) == open);
+                }
+            }
+        }
+        assert!(bob.global_map.is_cell_explored((-dx, -dy)));
+    }
+
+    #[test]
+    fn test_direction_between() {
+        assert_eq!(Navigator::direction_between((0, 0), (0, -1)), Some(RelativeDirection::Front));
+        assert_eq!(Navigator::direction_between((0, 0), (-1, 0)), Some(RelativeDirection::Left));
+        assert_eq!(Navigator::direction_between((0, 0), (2, 0)), None);
+    }
+
     #[test]
     fn test_compute_direction_from_angle() {
         assert_eq!(Navigator::compute_direction_from_angle(0.0), Some(RelativeDirection::Front));
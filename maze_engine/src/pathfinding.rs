@@ -0,0 +1,159 @@
+use crate::global_map::GlobalMap;
+use std::collections::{HashMap, VecDeque};
+
+/// Décalages vers les quatre cellules voisines (haut, droite, bas, gauche).
+const NEIGHBOURS: [(i32, i32); 4] = [(0, -1), (1, 0), (0, 1), (-1, 0)];
+
+/// Recherche le plus court chemin (BFS) depuis `start` vers la cellule la plus proche vérifiant `is_target`.
+///
+/// Seuls les passages connus et ouverts de la carte sont empruntés. La cellule cible peut en revanche
+/// être inexplorée : c'est ce qui permet de viser les frontières de la zone connue.
+///
+/// # Arguments
+///
+/// * `map` - La carte globale sur laquelle planifier.
+/// * `start` - La cellule de départ (relative au départ de la carte).
+/// * `is_target` - Prédicat identifiant les cellules recherchées.
+///
+/// # Retourne
+///
+/// * `Some(chemin)` avec les cellules à parcourir dans l'ordre, sans la cellule de départ
+///   (chemin vide si `start` est elle-même une cible).
+/// * `None` si aucune cible n'est atteignable.
+///
+/// # Exemple
+///
+/// ```
+/// use maze_engine::global_map::GlobalMap;
+/// use maze_engine::pathfinding::find_path;
+///
+/// let mut map = GlobalMap::new(10, 10);
+/// map.update_from_radar(&[[' '; 7]; 7], (0, 0));
+/// let path = find_path(&map, (0, 0), |cell| cell == (1, 1)).unwrap();
+/// assert_eq!(path.len(), 2);
+/// assert_eq!(path.last(), Some(&(1, 1)));
+/// ```
+pub fn find_path<F>(map: &GlobalMap, start: (i32, i32), is_target: F) -> Option<Vec<(i32, i32)>>
+where
+    F: Fn((i32, i32)) -> bool,
+{
+    let mut predecessors: HashMap<(i32, i32), (i32, i32)> = HashMap::new();
+    let mut queue = VecDeque::from([start]);
+    predecessors.insert(start, start);
+
+    while let Some(cell) = queue.pop_front() {
+        if is_target(cell) {
+            return Some(rebuild_path(&predecessors, start, cell));
+        }
+        for (dx, dy) in NEIGHBOURS {
+            let next = (cell.0 + dx, cell.1 + dy);
+            if predecessors.contains_key(&next) || !map.is_passage_open(cell, next) {
+                continue;
+            }
+            predecessors.insert(next, cell);
+            queue.push_back(next);
+        }
+    }
+    None
+}
+
+/// Calcule la distance (en nombre de déplacements) de `start` à chaque cellule atteignable.
+///
+/// Comme pour [`find_path`], seuls les passages connus et ouverts sont empruntés.
+///
+/// # Exemple
+///
+/// ```
+/// use maze_engine::global_map::GlobalMap;
+/// use maze_engine::pathfinding::distances_from;
+///
+/// let mut map = GlobalMap::new(10, 10);
+/// map.update_from_radar(&[[' '; 7]; 7], (0, 0));
+/// let distances = distances_from(&map, (0, 0));
+/// assert_eq!(distances[&(0, 0)], 0);
+/// assert_eq!(distances[&(1, 1)], 2);
+/// ```
+pub fn distances_from(map: &GlobalMap, start: (i32, i32)) -> HashMap<(i32, i32), usize> {
+    let mut distances = HashMap::from([(start, 0)]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some(cell) = queue.pop_front() {
+        let distance = distances[&cell];
+        for (dx, dy) in NEIGHBOURS {
+            let next = (cell.0 + dx, cell.1 + dy);
+            if distances.contains_key(&next) || !map.is_passage_open(cell, next) {
+                continue;
+            }
+            distances.insert(next, distance + 1);
+            queue.push_back(next);
+        }
+    }
+    distances
+}
+
+/// Reconstruit le chemin de `start` (exclu) à `target` (inclus) à partir des prédécesseurs du BFS.
+fn rebuild_path(
+    predecessors: &HashMap<(i32, i32), (i32, i32)>,
+    start: (i32, i32),
+    target: (i32, i32),
+) -> Vec<(i32, i32)> {
+    let mut path = Vec::new();
+    let mut cell = target;
+    while cell != start {
+        path.push(cell);
+        cell = predecessors[&cell];
+    }
+    path.reverse();
+    path
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Construit une vue radar entièrement ouverte, avec un mur vertical à droite du joueur.
+    fn radar_with_right_wall() -> [[char; 7]; 7] {
+        let mut radar_view = [[' '; 7]; 7];
+        radar_view[3][4] = '|';
+        radar_view[1][4] = '|';
+        radar_view[5][4] = '|';
+        radar_view
+    }
+
+    #[test]
+    fn test_find_path_start_is_target() {
+        let map = GlobalMap::new(10, 10);
+        assert_eq!(find_path(&map, (0, 0), |cell| cell == (0, 0)), Some(vec![]));
+    }
+
+    #[test]
+    fn test_find_path_goes_around_walls() {
+        let mut map = GlobalMap::new(10, 10);
+        map.update_from_radar(&radar_with_right_wall(), (0, 0));
+        // La cellule (1, 0) est derrière le mur : il faut passer par le haut ou le bas,
+        // mais ces passages sont aussi murés, donc la cellule est inaccessible.
+        assert_eq!(find_path(&map, (0, 0), |cell| cell == (1, 0)), None);
+        // La cellule (-1, -1) reste accessible en deux pas.
+        let path = find_path(&map, (0, 0), |cell| cell == (-1, -1)).unwrap();
+        assert_eq!(path.len(), 2);
+    }
+
+    #[test]
+    fn test_distances_from_stops_at_walls() {
+        let mut map = GlobalMap::new(10, 10);
+        map.update_from_radar(&radar_with_right_wall(), (0, 0));
+        let distances = distances_from(&map, (0, 0));
+        assert_eq!(distances.get(&(-1, 0)), Some(&1));
+        assert_eq!(distances.get(&(1, 0)), None);
+    }
+
+    #[test]
+    fn test_find_path_reaches_unexplored_cell() {
+        let mut map = GlobalMap::new(10, 10);
+        map.update_from_radar(&[[' '; 7]; 7], (0, 0));
+        let path = find_path(&map, (0, 0), |cell| !map.is_cell_explored(cell)).unwrap();
+        // La cellule inexplorée la plus proche est juste au-delà de la vue radar.
+        assert_eq!(path.len(), 2);
+        assert!(!map.is_cell_explored(*path.last().unwrap()));
+    }
+}
@@ -1,11 +1,64 @@
-use std::collections::HashMap;
+use commun::rules::CHALLENGE_ABANDON_PENALTY;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::time::Duration;
+
+/// Actions d'un joueur, hors déplacements réussis ou non (voir [`ScoreManager::add_move`]).
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+struct PlayerStats {
+    failed_moves: u32,
+    challenge_attempts: u32,
+    challenges_solved: u32,
+    challenges_abandoned: u32,
+    rescues_requested: u32,
+    rescues_performed: u32,
+    elapsed: Duration,
+}
+
+/// Détail du score d'un joueur.
+#[derive(Debug, Clone, Default, PartialEq, Serialize)]
+pub struct PlayerScore {
+    /// Déplacements envoyés au serveur, y compris ceux qu'il a refusés.
+    pub moves: u32,
+    /// Déplacements refusés par le serveur (mur, adversaire...).
+    pub failed_moves: u32,
+    /// Réponses envoyées aux défis.
+    pub challenge_attempts: u32,
+    /// Défis résolus.
+    pub challenges_solved: u32,
+    /// Défis abandonnés, chacun pénalisé de `CHALLENGE_ABANDON_PENALTY` déplacements.
+    pub challenges_abandoned: u32,
+    /// Appels à l'aide (défi `SOS`).
+    pub rescues_requested: u32,
+    /// Coéquipiers secourus.
+    pub rescues_performed: u32,
+    /// Durée de la partie du joueur, en secondes.
+    pub elapsed_secs: f64,
+}
+
+/// Rapport de score d'une équipe, sérialisable pour comparer les stratégies entre versions.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct ScoreReport {
+    pub team_size: u32,
+    /// Score officiel de l'équipe (voir [`ScoreManager::team_score`]).
+    pub team_score: u32,
+    /// Moyenne des déplacements par joueur (voir [`ScoreManager::average_moves`]).
+    pub average_moves: f64,
+    /// Détail par joueur (`player_id` -> score).
+    pub players: BTreeMap<u32, PlayerScore>,
+}
 
 /// Gère le score en suivant les mouvements des joueurs.
 ///
-/// Chaque joueur accumule un nombre de mouvements, et le score moyen est calculé en fonction de la taille de l'équipe.
+/// Chaque joueur accumule un nombre de mouvements ; le score de l'équipe est le total de ses déplacements,
+/// pénalités comprises, comme dans le classement du serveur.
+/// Les déplacements refusés, les défis, les secours et la durée de jeu sont également suivis par joueur,
+/// pour le rapport détaillé ([`ScoreManager::report`]).
 pub struct ScoreManager {
     /// Stocke le nombre de déplacements effectués par chaque joueur (`player_id` -> `nombre de déplacements`).
     player_moves: HashMap<u32, u32>,
+    /// Autres actions de chaque joueur.
+    player_stats: HashMap<u32, PlayerStats>,
     /// Nombre total de joueurs dans l'équipe.
     team_size: u32,
 }
@@ -21,6 +74,7 @@ impl ScoreManager {
     pub fn new(team_size: u32) -> Self {
         Self {
             player_moves: HashMap::new(),
+            player_stats: HashMap::new(),
             team_size,
         }
     }
@@ -36,12 +90,112 @@ impl ScoreManager {
         *counter += 1;
     }
 
-    /// Calcule le score final (moyenne des mouvements par joueur).
+    /// Enregistre un déplacement refusé par le serveur. Le déplacement reste compté par [`ScoreManager::add_move`].
+    pub fn add_failed_move(&mut self, player_id: u32) {
+        self.player_stats.entry(player_id).or_default().failed_moves += 1;
+    }
+
+    /// Enregistre une réponse envoyée à un défi.
+    pub fn add_challenge_attempt(&mut self, player_id: u32) {
+        self.player_stats.entry(player_id).or_default().challenge_attempts += 1;
+    }
+
+    /// Enregistre un défi résolu.
+    pub fn add_challenge_solved(&mut self, player_id: u32) {
+        self.player_stats.entry(player_id).or_default().challenges_solved += 1;
+    }
+
+    /// Enregistre l'abandon d'un défi, pénalisé de `CHALLENGE_ABANDON_PENALTY` déplacements.
+    pub fn add_challenge_abandoned(&mut self, player_id: u32) {
+        self.player_stats.entry(player_id).or_default().challenges_abandoned += 1;
+    }
+
+    /// Enregistre un appel à l'aide d'un joueur en détresse.
+    pub fn add_rescue_request(&mut self, player_id: u32) {
+        self.player_stats.entry(player_id).or_default().rescues_requested += 1;
+    }
+
+    /// Enregistre le secours d'un coéquipier par un joueur.
+    pub fn add_rescue(&mut self, player_id: u32) {
+        self.player_stats.entry(player_id).or_default().rescues_performed += 1;
+    }
+
+    /// Enregistre la durée de la partie d'un joueur.
+    pub fn set_elapsed(&mut self, player_id: u32, elapsed: Duration) {
+        self.player_stats.entry(player_id).or_default().elapsed = elapsed;
+    }
+
+    /// Retourne le détail du score d'un joueur.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use maze_engine::scoring::ScoreManager;
+    /// let mut score_manager = ScoreManager::new(2);
+    /// score_manager.add_move(1);
+    /// score_manager.add_move(1);
+    /// score_manager.add_failed_move(1);
+    /// let score = score_manager.player_score(1);
+    /// assert_eq!((score.moves, score.failed_moves), (2, 1));
+    /// ```
+    pub fn player_score(&self, player_id: u32) -> PlayerScore {
+        let stats = self.player_stats.get(&player_id).copied().unwrap_or_default();
+        PlayerScore {
+            moves: self.player_moves.get(&player_id).copied().unwrap_or(0),
+            failed_moves: stats.failed_moves,
+            challenge_attempts: stats.challenge_attempts,
+            challenges_solved: stats.challenges_solved,
+            challenges_abandoned: stats.challenges_abandoned,
+            rescues_requested: stats.rescues_requested,
+            rescues_performed: stats.rescues_performed,
+            elapsed_secs: stats.elapsed.as_secs_f64(),
+        }
+    }
+
+    /// Produit le rapport de score de l'équipe, avec le détail de chaque joueur ayant joué.
+    pub fn report(&self) -> ScoreReport {
+        let players = self
+            .player_moves
+            .keys()
+            .chain(self.player_stats.keys())
+            .map(|&player_id| (player_id, self.player_score(player_id)))
+            .collect();
+        ScoreReport {
+            team_size: self.team_size,
+            team_score: self.team_score(),
+            average_moves: self.average_moves(),
+            players,
+        }
+    }
+
+    /// Calcule le score officiel de l'équipe, tel que le classe le serveur : plus il est bas, meilleure est la partie.
+    ///
+    /// C'est le total des déplacements envoyés par les joueurs, y compris ceux que le serveur a refusés
+    /// (déjà comptés par [`ScoreManager::add_move`]), plus `CHALLENGE_ABANDON_PENALTY` déplacements par défi abandonné.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use maze_engine::scoring::ScoreManager;
+    /// let mut score_manager = ScoreManager::new(2);
+    /// score_manager.add_move(1);
+    /// score_manager.add_move(2);
+    /// score_manager.add_failed_move(2);
+    /// score_manager.add_challenge_abandoned(1);
+    /// assert_eq!(score_manager.team_score(), 12);
+    /// ```
+    pub fn team_score(&self) -> u32 {
+        let moves: u32 = self.player_moves.values().sum();
+        let abandoned: u32 = self.player_stats.values().map(|stats| stats.challenges_abandoned).sum();
+        moves + abandoned * CHALLENGE_ABANDON_PENALTY
+    }
+
+    /// Calcule la moyenne des mouvements par joueur, conservée pour comparer les versions précédentes.
     ///
     /// # Retourne
     /// - `0.0` si la taille de l'équipe est `0` (évite une division par zéro).
     /// - Sinon, la moyenne des mouvements effectués par l'ensemble de l'équipe.
-    pub fn compute_score(&self) -> f64 {
+    pub fn average_moves(&self) -> f64 {
         if self.team_size == 0 {
             return 0.0; // Évite une division par zéro
         }
@@ -68,26 +222,41 @@ mod tests {
     }
 
     #[test]
-    fn test_compute_score() {
+    fn test_average_moves() {
         let mut score_manager = ScoreManager::new(3);
         score_manager.add_move(1);
         score_manager.add_move(1);
         score_manager.add_move(2);
 
         let expected_score = (2 + 1) as f64 / 3.0;
-        assert_eq!(score_manager.compute_score(), expected_score);
+        assert_eq!(score_manager.average_moves(), expected_score);
     }
 
     #[test]
-    fn test_compute_score_with_no_moves() {
+    fn test_average_moves_with_no_moves() {
         let score_manager = ScoreManager::new(3);
-        assert_eq!(score_manager.compute_score(), 0.0);
+        assert_eq!(score_manager.average_moves(), 0.0);
     }
 
     #[test]
-    fn test_compute_score_with_no_players() {
+    fn test_average_moves_with_no_players() {
         let score_manager = ScoreManager::new(0);
-        assert_eq!(score_manager.compute_score(), 0.0);
+        assert_eq!(score_manager.average_moves(), 0.0);
+    }
+
+    #[test]
+    fn test_team_score_counts_failed_moves_and_penalties() {
+        let mut score_manager = ScoreManager::new(3);
+        for _ in 0..4 {
+            score_manager.add_move(1);
+        }
+        score_manager.add_move(2);
+        score_manager.add_failed_move(1);
+        score_manager.add_challenge_abandoned(2);
+        score_manager.add_challenge_abandoned(2);
+
+        assert_eq!(score_manager.team_score(), 5 + 2 * CHALLENGE_ABANDON_PENALTY);
+        assert_eq!(ScoreManager::new(3).team_score(), 0);
     }
 
     #[test]
@@ -101,7 +270,40 @@ mod tests {
         score_manager.add_move(3);
 
         let expected_score = (2 + 1 + 3) as f64 / 4.0;
-        assert_eq!(score_manager.compute_score(), expected_score);
+        assert_eq!(score_manager.average_moves(), expected_score);
+    }
+
+    #[test]
+    fn test_report_breakdown() {
+        let mut score_manager = ScoreManager::new(2);
+        score_manager.add_move(1);
+        score_manager.add_move(1);
+        score_manager.add_failed_move(1);
+        score_manager.add_challenge_attempt(2);
+        score_manager.add_challenge_attempt(2);
+        score_manager.add_challenge_solved(2);
+        score_manager.add_rescue_request(2);
+        score_manager.add_rescue(1);
+        score_manager.set_elapsed(2, Duration::from_millis(1500));
+
+        let report = score_manager.report();
+        assert_eq!(report.team_score, 2);
+        assert_eq!(report.average_moves, 1.0);
+        assert_eq!(report.players.len(), 2);
+        assert_eq!(
+            report.players[&1],
+            PlayerScore { moves: 2, failed_moves: 1, rescues_performed: 1, ..PlayerScore::default() }
+        );
+        assert_eq!(
+            report.players[&2],
+            PlayerScore {
+                challenge_attempts: 2,
+                challenges_solved: 1,
+                rescues_requested: 1,
+                elapsed_secs: 1.5,
+                ..PlayerScore::default()
+            }
+        );
     }
 
     #[test]
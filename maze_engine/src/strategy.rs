@@ -0,0 +1,803 @@
+use crate::global_map::{GlobalMap, Knowledge};
+use crate::navigation::{Navigator, MAX_FAILS};
+use crate::pathfinding::{distances_from, find_path};
+use commun::structs::RelativeDirection;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::fmt;
+use std::str::FromStr;
+
+/// Directions dans le sens des aiguilles d'une montre, en partant de `Front`.
+const CLOCKWISE: [RelativeDirection; 4] = [
+    RelativeDirection::Front,
+    RelativeDirection::Right,
+    RelativeDirection::Back,
+    RelativeDirection::Left,
+];
+
+/// Passage entre deux cellules adjacentes, identifié par la paire de cellules ordonnée.
+type Passage = ((i32, i32), (i32, i32));
+
+/// Vue en lecture seule de l'état du `Navigator`, transmise à la stratégie à chaque décision.
+///
+/// La vue radar a déjà été intégrée à `global_map` lorsque le contexte est construit.
+pub struct NavigationContext<'a> {
+    /// La vue radar 7x7 courante.
+    pub radar_view: &'a [[char; 7]; 7],
+    /// Position actuelle du joueur.
+    pub position: (i32, i32),
+    /// Dernière direction empruntée.
+    pub last_direction: Option<RelativeDirection>,
+    /// Historique des déplacements effectués.
+    pub movement_history: &'a VecDeque<RelativeDirection>,
+    /// Positions déjà visitées.
+    pub visited_positions: &'a HashSet<(i32, i32)>,
+    /// Nombre d'échecs enregistrés pour chaque direction.
+    pub fail_count: &'a HashMap<RelativeDirection, usize>,
+    /// Directions temporairement bannies.
+    pub banned_directions: &'a HashSet<RelativeDirection>,
+    /// Carte globale connue.
+    pub global_map: &'a GlobalMap,
+    /// Direction absolue estimée de la sortie (indice boussole), en degrés.
+    pub exit_bearing: Option<f32>,
+    /// Poids de l'indice boussole.
+    pub compass_weight: f32,
+    /// Frontières déjà visées par des coéquipiers, à éviter dans la mesure du possible.
+    pub reserved_cells: &'a HashSet<(i32, i32)>,
+}
+
+impl NavigationContext<'_> {
+    /// Retourne la cellule voisine de la position actuelle dans la direction donnée.
+    pub fn neighbour(&self, direction: RelativeDirection) -> (i32, i32) {
+        Navigator::calculate_new_position(self.position, direction)
+    }
+
+    /// Retourne l'état de connaissance du passage dans la direction donnée.
+    pub fn passage_state(&self, direction: RelativeDirection) -> Knowledge {
+        self.global_map.passage_state(self.position, self.neighbour(direction))
+    }
+
+    /// Indique si le passage dans la direction donnée est connu et ouvert sur la carte.
+    pub fn is_passage_open(&self, direction: RelativeDirection) -> bool {
+        self.global_map.is_passage_open(self.position, self.neighbour(direction))
+    }
+}
+
+/// Algorithme de choix des déplacements utilisé par le `Navigator`.
+///
+/// Le `Navigator` applique les déplacements de manière optimiste : `on_move` est appelé dès qu'un
+/// déplacement est envoyé, puis `on_move_failure` si le serveur le refuse.
+pub trait NavigationStrategy: Send {
+    /// Nom court de la stratégie, tel qu'accepté par la ligne de commande du client.
+    fn name(&self) -> &'static str;
+
+    /// Choisit la prochaine direction.
+    ///
+    /// # Retourne
+    ///
+    /// `None` si la stratégie n'a aucune proposition : le `Navigator` effectue alors un demi-tour.
+    fn next_move(&mut self, context: &NavigationContext) -> Option<RelativeDirection>;
+
+    /// Cellule visée par la stratégie au-delà du prochain pas, réservée auprès des coéquipiers.
+    fn target(&self) -> Option<(i32, i32)> {
+        None
+    }
+
+    /// Notifie la stratégie qu'un déplacement de `from` vers `to` a été effectué.
+    fn on_move(&mut self, _from: (i32, i32), _to: (i32, i32)) {}
+
+    /// Notifie la stratégie que le déplacement de `from` vers `to` a été refusé par le serveur.
+    fn on_move_failure(&mut self, _from: (i32, i32), _to: (i32, i32)) {}
+}
+
+/// Stratégies disponibles, sélectionnables par leur nom.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExplorationStrategy {
+    /// Règle locale gloutonne : privilégie les cases non visitées visibles sur le radar.
+    Greedy,
+    /// Suivi du mur à main gauche.
+    LeftHand,
+    /// Suivi du mur à main droite.
+    RightHand,
+    /// Algorithme de Pledge : avance dans une direction principale et longe les obstacles en comptant les virages.
+    Pledge,
+    /// Algorithme de Trémaux : chaque passage est marqué à chaque traversée et n'est jamais emprunté
+    /// plus de deux fois, ce qui garantit la fin de l'exploration sur tout labyrinthe fini, même avec des cycles.
+    Tremaux,
+    /// Se dirige vers la frontière inexplorée la plus proche en suivant le plus court chemin connu.
+    Frontier,
+}
+
+impl ExplorationStrategy {
+    /// Toutes les stratégies disponibles.
+    pub const ALL: [ExplorationStrategy; 6] = [
+        ExplorationStrategy::Greedy,
+        ExplorationStrategy::LeftHand,
+        ExplorationStrategy::RightHand,
+        ExplorationStrategy::Pledge,
+        ExplorationStrategy::Tremaux,
+        ExplorationStrategy::Frontier,
+    ];
+
+    /// Nom court de la stratégie.
+    pub fn name(self) -> &'static str {
+        match self {
+            ExplorationStrategy::Greedy => "greedy",
+            ExplorationStrategy::LeftHand => "left-hand",
+            ExplorationStrategy::RightHand => "right-hand",
+            ExplorationStrategy::Pledge => "pledge",
+            ExplorationStrategy::Tremaux => "tremaux",
+            ExplorationStrategy::Frontier => "frontier",
+        }
+    }
+
+    /// Construit une instance de la stratégie.
+    pub fn build(self) -> Box<dyn NavigationStrategy> {
+        match self {
+            ExplorationStrategy::Greedy => Box::new(GreedyStrategy),
+            ExplorationStrategy::LeftHand => Box::new(WallFollowerStrategy::new(WallSide::Left)),
+            ExplorationStrategy::RightHand => Box::new(WallFollowerStrategy::new(WallSide::Right)),
+            ExplorationStrategy::Pledge => Box::new(PledgeStrategy::new()),
+            ExplorationStrategy::Tremaux => Box::new(TremauxStrategy::new()),
+            ExplorationStrategy::Frontier => Box::new(FrontierStrategy::new()),
+        }
+    }
+}
+
+impl fmt::Display for ExplorationStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for ExplorationStrategy {
+    type Err = String;
+
+    /// Analyse le nom court d'une stratégie.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use maze_engine::strategy::ExplorationStrategy;
+    /// assert_eq!("pledge".parse(), Ok(ExplorationStrategy::Pledge));
+    /// assert!("unknown".parse::<ExplorationStrategy>().is_err());
+    /// ```
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|strategy| strategy.name() == name)
+            .ok_or_else(|| {
+                let names: Vec<&str> = Self::ALL.iter().map(|strategy| strategy.name()).collect();
+                format!("Stratégie inconnue '{}' (disponibles : {})", name, names.join(", "))
+            })
+    }
+}
+
+/// Règle locale gloutonne.
+///
+/// L'algorithme privilégie les cases non visitées et évite de revenir sur ses pas (demi-tour)
+/// si une autre option est disponible.
+pub struct GreedyStrategy;
+
+impl NavigationStrategy for GreedyStrategy {
+    fn name(&self) -> &'static str {
+        ExplorationStrategy::Greedy.name()
+    }
+
+    fn next_move(&mut self, context: &NavigationContext) -> Option<RelativeDirection> {
+        // Démarrer avec toutes les directions possibles
+        let mut possible_moves = vec![
+            RelativeDirection::Front,
+            RelativeDirection::Right,
+            RelativeDirection::Left,
+            RelativeDirection::Back,
+        ];
+
+        // Filtrer selon les directions bannies, celles ayant trop d'échecs ou murées d'après la carte.
+        // Un passage encore inconnu reste une option : seul un mur observé l'exclut.
+        possible_moves.retain(|&dir| {
+            let fails = context.fail_count.get(&dir).cloned().unwrap_or(0);
+            fails < MAX_FAILS
+                && !context.banned_directions.contains(&dir)
+                && context.passage_state(dir) != Knowledge::Wall
+        });
+
+        // Si plusieurs options sont disponibles, éviter le demi-tour (la direction opposée à la dernière)
+        if possible_moves.len() > 1 {
+            if let Some(last_dir) = context.last_direction {
+                let reverse_dir = Navigator::turn_back(last_dir);
+                if possible_moves.contains(&reverse_dir) {
+                    possible_moves.retain(|&dir| dir != reverse_dir);
+                }
+            }
+        }
+
+        // À défaut de mieux, tenter d'abord la direction indiquée par la boussole.
+        if let Some(compass_dir) = context.exit_bearing.and_then(Navigator::compute_direction_from_angle) {
+            possible_moves.sort_by_key(|&dir| dir != compass_dir);
+        }
+
+        // Préférer une direction qui mène vers une case non visitée
+        if let Some(&dir) = possible_moves
+            .iter()
+            .find(|&&dir| !context.visited_positions.contains(&context.neighbour(dir)))
+        {
+            return Some(dir);
+        }
+
+        // Si toutes les options mènent vers des cases déjà visitées, choisir la moins parcourue, puis celle avec le moins d'échecs
+        possible_moves.sort_by_key(|&dir| {
+            let visits = context.global_map.cell_info(context.neighbour(dir)).visits;
+            (visits, context.fail_count.get(&dir).cloned().unwrap_or(0))
+        });
+        possible_moves.first().copied()
+    }
+}
+
+/// Côté du mur suivi par un `WallFollowerStrategy`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WallSide {
+    Left,
+    Right,
+}
+
+/// Suivi de mur : garde toujours le mur du même côté, en tournant de préférence de ce côté.
+///
+/// L'orientation utilisée est celle du dernier déplacement effectué.
+pub struct WallFollowerStrategy {
+    side: WallSide,
+    heading: RelativeDirection,
+    previous_heading: RelativeDirection,
+}
+
+impl WallFollowerStrategy {
+    /// Crée un suivi de mur du côté donné, orienté initialement vers `Front`.
+    pub fn new(side: WallSide) -> Self {
+        Self {
+            side,
+            heading: RelativeDirection::Front,
+            previous_heading: RelativeDirection::Front,
+        }
+    }
+}
+
+impl NavigationStrategy for WallFollowerStrategy {
+    fn name(&self) -> &'static str {
+        match self.side {
+            WallSide::Left => ExplorationStrategy::LeftHand.name(),
+            WallSide::Right => ExplorationStrategy::RightHand.name(),
+        }
+    }
+
+    fn next_move(&mut self, context: &NavigationContext) -> Option<RelativeDirection> {
+        // Ordre de préférence en quarts de tour horaires depuis l'orientation courante.
+        let turns = match self.side {
+            WallSide::Left => [3, 0, 1, 2],
+            WallSide::Right => [1, 0, 3, 2],
+        };
+        turns
+            .into_iter()
+            .map(|turn| rotate(self.heading, turn))
+            .find(|&direction| context.is_passage_open(direction))
+    }
+
+    fn on_move(&mut self, from: (i32, i32), to: (i32, i32)) {
+        if let Some(direction) = Navigator::direction_between(from, to) {
+            self.previous_heading = self.heading;
+            self.heading = direction;
+        }
+    }
+
+    fn on_move_failure(&mut self, _from: (i32, i32), _to: (i32, i32)) {
+        self.heading = self.previous_heading;
+    }
+}
+
+/// Algorithme de Pledge.
+///
+/// Le joueur avance dans une direction principale (celle de la boussole si elle est connue au premier
+/// choix, `Front` sinon). Face à un obstacle, il longe le mur à main gauche en comptant ses virages
+/// (+1 à droite, -1 à gauche) et ne reprend la direction principale que lorsque le compteur revient à zéro.
+pub struct PledgeStrategy {
+    main_direction: Option<RelativeDirection>,
+    heading: RelativeDirection,
+    turn_count: i32,
+    /// Virage prévu pour le déplacement proposé, appliqué dans `on_move`.
+    pending_turn: Option<(RelativeDirection, i32)>,
+    /// État précédent (orientation, compteur), restauré si le déplacement est refusé.
+    previous_state: (RelativeDirection, i32),
+}
+
+impl PledgeStrategy {
+    /// Crée une stratégie de Pledge sans direction principale encore choisie.
+    pub fn new() -> Self {
+        Self {
+            main_direction: None,
+            heading: RelativeDirection::Front,
+            turn_count: 0,
+            pending_turn: None,
+            previous_state: (RelativeDirection::Front, 0),
+        }
+    }
+
+    /// Retourne le compteur de virages courant.
+    pub fn turn_count(&self) -> i32 {
+        self.turn_count
+    }
+}
+
+impl Default for PledgeStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NavigationStrategy for PledgeStrategy {
+    fn name(&self) -> &'static str {
+        ExplorationStrategy::Pledge.name()
+    }
+
+    fn next_move(&mut self, context: &NavigationContext) -> Option<RelativeDirection> {
+        let main_direction = *self.main_direction.get_or_insert_with(|| {
+            context
+                .exit_bearing
+                .and_then(Navigator::compute_direction_from_angle)
+                .unwrap_or(RelativeDirection::Front)
+        });
+
+        // Hors suivi de mur : avancer dans la direction principale, ou tourner à droite face à l'obstacle.
+        // En suivi de mur : règle de la main gauche.
+        let turns: &[i32] = if self.turn_count == 0 {
+            self.heading = main_direction;
+            &[0, 1, 2, 3]
+        } else {
+            &[-1, 0, 1, 2]
+        };
+
+        let (direction, turn) = turns
+            .iter()
+            .map(|&turn| (rotate(self.heading, turn.rem_euclid(4) as usize), turn))
+            .find(|&(direction, _)| context.is_passage_open(direction))?;
+        self.pending_turn = Some((direction, turn));
+        Some(direction)
+    }
+
+    fn on_move(&mut self, from: (i32, i32), to: (i32, i32)) {
+        let Some(direction) = Navigator::direction_between(from, to) else {
+            return;
+        };
+        let turn = match self.pending_turn.take() {
+            Some((planned, turn)) if planned == direction => turn,
+            _ => signed_turn(self.heading, direction),
+        };
+        self.previous_state = (self.heading, self.turn_count);
+        self.heading = direction;
+        self.turn_count += turn;
+    }
+
+    fn on_move_failure(&mut self, _from: (i32, i32), _to: (i32, i32)) {
+        (self.heading, self.turn_count) = self.previous_state;
+    }
+}
+
+/// Algorithme de Trémaux.
+///
+/// - En arrivant par un passage marqué une seule fois sur une cellule déjà parcourue (un autre passage
+///   est marqué), le joueur fait demi-tour.
+/// - Sinon, il emprunte le passage ouvert le moins marqué, en évitant le passage d'arrivée si possible.
+/// - Un passage marqué deux fois n'est plus jamais emprunté.
+///
+/// Lorsque tous les passages accessibles sont marqués deux fois, l'exploration est terminée et la
+/// règle gloutonne prend le relais.
+pub struct TremauxStrategy {
+    /// Nombre de traversées de chaque passage.
+    passage_marks: HashMap<Passage, u8>,
+    fallback: GreedyStrategy,
+}
+
+impl TremauxStrategy {
+    /// Crée une stratégie de Trémaux sans aucun passage marqué.
+    pub fn new() -> Self {
+        Self {
+            passage_marks: HashMap::new(),
+            fallback: GreedyStrategy,
+        }
+    }
+
+    /// Retourne le nombre de traversées enregistrées pour le passage entre deux cellules adjacentes.
+    pub fn passage_marks(&self, from: (i32, i32), to: (i32, i32)) -> u8 {
+        self.passage_marks.get(&passage_key(from, to)).copied().unwrap_or(0)
+    }
+}
+
+impl Default for TremauxStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NavigationStrategy for TremauxStrategy {
+    fn name(&self) -> &'static str {
+        ExplorationStrategy::Tremaux.name()
+    }
+
+    fn next_move(&mut self, context: &NavigationContext) -> Option<RelativeDirection> {
+        let position = context.position;
+        let entry = context
+            .movement_history
+            .back()
+            .map(|&direction| Navigator::calculate_new_position(position, Navigator::turn_back(direction)));
+
+        let open_passages: Vec<(RelativeDirection, (i32, i32))> = CLOCKWISE
+            .into_iter()
+            .filter(|&direction| context.is_passage_open(direction))
+            .map(|direction| (direction, context.neighbour(direction)))
+            .collect();
+
+        let known_junction = open_passages
+            .iter()
+            .any(|&(_, cell)| Some(cell) != entry && self.passage_marks(position, cell) > 0);
+
+        let choice = match entry {
+            Some(entry_cell)
+                if known_junction
+                    && self.passage_marks(position, entry_cell) == 1
+                    && open_passages.iter().any(|&(_, cell)| cell == entry_cell) =>
+            {
+                open_passages.iter().copied().find(|&(_, cell)| cell == entry_cell)
+            }
+            _ => open_passages
+                .iter()
+                .copied()
+                .filter(|&(_, cell)| self.passage_marks(position, cell) < 2)
+                .min_by_key(|&(_, cell)| (self.passage_marks(position, cell), Some(cell) == entry)),
+        };
+
+        match choice {
+            Some((direction, _)) => Some(direction),
+            None => {
+                crate::log!("[Navigator] Tremaux exploration exhausted, falling back to greedy rule.");
+                self.fallback.next_move(context)
+            }
+        }
+    }
+
+    fn on_move(&mut self, from: (i32, i32), to: (i32, i32)) {
+        *self.passage_marks.entry(passage_key(from, to)).or_insert(0) += 1;
+    }
+
+    fn on_move_failure(&mut self, from: (i32, i32), to: (i32, i32)) {
+        let key = passage_key(from, to);
+        if let Some(marks) = self.passage_marks.get_mut(&key) {
+            *marks -= 1;
+            if *marks == 0 {
+                self.passage_marks.remove(&key);
+            }
+        }
+    }
+}
+
+/// Exploration par frontières.
+///
+/// Le joueur suit le chemin planifié vers la cellule inexplorée la plus proche (ou la mieux alignée avec
+/// la boussole, voir [`Navigator::set_compass_weight`]). Le chemin est recalculé dès qu'il n'est plus
+/// valide : mur découvert sur le trajet ou cible révélée par le radar. Lorsqu'aucune frontière n'est
+/// atteignable, la règle gloutonne prend le relais.
+pub struct FrontierStrategy {
+    /// Cellules restant à parcourir pour atteindre la frontière visée.
+    planned_path: VecDeque<(i32, i32)>,
+    fallback: GreedyStrategy,
+}
+
+impl FrontierStrategy {
+    /// Crée une stratégie d'exploration par frontières sans chemin planifié.
+    pub fn new() -> Self {
+        Self {
+            planned_path: VecDeque::new(),
+            fallback: GreedyStrategy,
+        }
+    }
+
+    /// Retourne les cellules restant à parcourir sur le chemin planifié.
+    pub fn planned_path(&self) -> &VecDeque<(i32, i32)> {
+        &self.planned_path
+    }
+
+    /// Vérifie que le chemin planifié mène toujours à une cellule inexplorée par des passages ouverts.
+    fn is_planned_path_valid(&self, context: &NavigationContext) -> bool {
+        let Some(&target) = self.planned_path.back() else {
+            return false;
+        };
+        if context.global_map.is_cell_explored(target) || context.reserved_cells.contains(&target) {
+            return false;
+        }
+        let mut previous = context.position;
+        for &cell in &self.planned_path {
+            if !context.global_map.is_passage_open(previous, cell) {
+                return false;
+            }
+            previous = cell;
+        }
+        true
+    }
+
+    /// Planifie le chemin vers une frontière, en évitant celles réservées par les coéquipiers s'il en reste d'autres.
+    fn plan_path(context: &NavigationContext) -> Option<Vec<(i32, i32)>> {
+        let map = context.global_map;
+        let plan = |is_target: &dyn Fn((i32, i32)) -> bool| match context.exit_bearing {
+            Some(bearing) if context.compass_weight > 0.0 => Self::plan_path_with_compass(context, bearing, is_target),
+            _ => find_path(map, context.position, is_target),
+        };
+        plan(&|cell| is_frontier(map, cell) && !context.reserved_cells.contains(&cell))
+            .or_else(|| plan(&|cell| is_frontier(map, cell)))
+    }
+
+    /// Choisit la cible atteignable minimisant `distance - poids * alignement` et planifie le chemin vers elle.
+    ///
+    /// L'alignement est le cosinus de l'angle entre la direction de la cible et celle de la sortie.
+    fn plan_path_with_compass(
+        context: &NavigationContext,
+        bearing: f32,
+        is_target: &dyn Fn((i32, i32)) -> bool,
+    ) -> Option<Vec<(i32, i32)>> {
+        let map = context.global_map;
+        let (exit_x, exit_y) = (bearing.to_radians().sin(), -bearing.to_radians().cos());
+        let cost = |&(cell, distance): &((i32, i32), usize)| {
+            let dx = (cell.0 - context.position.0) as f32;
+            let dy = (cell.1 - context.position.1) as f32;
+            let alignment = (dx * exit_x + dy * exit_y) / (dx * dx + dy * dy).sqrt();
+            distance as f32 - context.compass_weight * alignment
+        };
+        let target = distances_from(map, context.position)
+            .into_iter()
+            .filter(|&(cell, _)| is_target(cell))
+            .min_by(|a, b| cost(a).total_cmp(&cost(b)).then(a.0.cmp(&b.0)))?
+            .0;
+        find_path(map, context.position, |cell| cell == target)
+    }
+}
+
+impl Default for FrontierStrategy {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NavigationStrategy for FrontierStrategy {
+    fn name(&self) -> &'static str {
+        ExplorationStrategy::Frontier.name()
+    }
+
+    fn next_move(&mut self, context: &NavigationContext) -> Option<RelativeDirection> {
+        if !self.is_planned_path_valid(context) {
+            self.planned_path = Self::plan_path(context).unwrap_or_default().into();
+            crate::log!("[Navigator] Planned path to frontier: {:?}", self.planned_path);
+        }
+
+        let direction = self
+            .planned_path
+            .pop_front()
+            .and_then(|next_position| Navigator::direction_between(context.position, next_position));
+
+        match direction {
+            Some(direction) => Some(direction),
+            None => {
+                crate::log!("[Navigator] No reachable frontier, falling back to greedy rule.");
+                self.planned_path.clear();
+                self.fallback.next_move(context)
+            }
+        }
+    }
+
+    fn target(&self) -> Option<(i32, i32)> {
+        self.planned_path.back().copied()
+    }
+
+    fn on_move_failure(&mut self, _from: (i32, i32), _to: (i32, i32)) {
+        self.planned_path.clear();
+    }
+}
+
+/// Une frontière est une cellule du labyrinthe dont l'état est encore inconnu.
+fn is_frontier(map: &GlobalMap, cell: (i32, i32)) -> bool {
+    map.contains_cell(cell) && map.cell_state(cell) == Knowledge::Unknown
+}
+
+/// Clé d'un passage, indépendante du sens de traversée.
+fn passage_key(a: (i32, i32), b: (i32, i32)) -> Passage {
+    if a <= b {
+        (a, b)
+    } else {
+        (b, a)
+    }
+}
+
+/// Fait pivoter une direction de `quarter_turns` quarts de tour dans le sens horaire.
+fn rotate(direction: RelativeDirection, quarter_turns: usize) -> RelativeDirection {
+    let index = CLOCKWISE.iter().position(|&d| d == direction).unwrap_or(0);
+    CLOCKWISE[(index + quarter_turns) % 4]
+}
+
+/// Virage signé (en quarts de tour, entre -1 et 2) pour passer d'une orientation à une autre.
+fn signed_turn(from: RelativeDirection, to: RelativeDirection) -> i32 {
+    let from = CLOCKWISE.iter().position(|&d| d == from).unwrap_or(0) as i32;
+    let to = CLOCKWISE.iter().position(|&d| d == to).unwrap_or(0) as i32;
+    match (to - from).rem_euclid(4) {
+        3 => -1,
+        turn => turn,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Labyrinthe tressé 3x3 (plusieurs cycles), au format des vues radar. La sortie est en bas à droite.
+    const BRAIDED_MAZE: [&str; 7] = [
+        "•-•-•-•",
+        "|     |",
+        "• •-• •",
+        "|     |",
+        "• • • •",
+        "| |  G|",
+        "•-•-•-•",
+    ];
+
+    /// Extrait la vue radar 7x7 centrée sur une cellule du labyrinthe de test.
+    fn radar_from_maze(maze: &[&str], (x, y): (i32, i32)) -> [[char; 7]; 7] {
+        let grid: Vec<Vec<char>> = maze.iter().map(|line| line.chars().collect()).collect();
+        let mut radar_view = [['#'; 7]; 7];
+        for (row, line) in radar_view.iter_mut().enumerate() {
+            for (column, content) in line.iter_mut().enumerate() {
+                let grid_x = 2 * x + column as i32 - 2;
+                let grid_y = 2 * y + row as i32 - 2;
+                if let Some(&c) = grid.get(grid_y as usize).and_then(|l| l.get(grid_x as usize)) {
+                    *content = c;
+                }
+            }
+        }
+        radar_view
+    }
+
+    /// Simule une partie : les déplacements à travers un mur sont refusés comme le ferait le serveur.
+    ///
+    /// Retourne le nombre de déplacements nécessaires pour atteindre la sortie, et les traversées de chaque passage.
+    fn simulate(
+        maze: &[&str],
+        navigator: &mut Navigator,
+        start: (i32, i32),
+        max_moves: usize,
+    ) -> (Option<usize>, HashMap<Passage, u8>) {
+        let mut position = start;
+        let mut traversals = HashMap::new();
+        for moves in 1..=max_moves {
+            let direction = navigator.choose_next_move(&radar_from_maze(maze, position));
+            let next = Navigator::calculate_new_position(position, direction);
+            let passage = maze[(position.1 + next.1 + 1) as usize]
+                .chars()
+                .nth((position.0 + next.0 + 1) as usize)
+                .unwrap_or('#');
+            if passage != ' ' {
+                navigator.handle_move_failure(direction);
+                continue;
+            }
+            *traversals.entry(passage_key(position, next)).or_insert(0) += 1;
+            position = next;
+            let cell = maze[(2 * position.1 + 1) as usize].chars().nth((2 * position.0 + 1) as usize);
+            if cell == Some('G') {
+                return (Some(moves), traversals);
+            }
+        }
+        (None, traversals)
+    }
+
+    #[test]
+    fn test_parse_strategy_names() {
+        for strategy in ExplorationStrategy::ALL {
+            assert_eq!(strategy.name().parse(), Ok(strategy));
+            assert_eq!(strategy.build().name(), strategy.name());
+        }
+        assert!("dfs".parse::<ExplorationStrategy>().is_err());
+    }
+
+    #[test]
+    fn test_every_strategy_reaches_exit() {
+        for strategy in ExplorationStrategy::ALL {
+            let mut navigator = Navigator::with_strategy(strategy);
+            let (moves, _) = simulate(&BRAIDED_MAZE, &mut navigator, (0, 0), 200);
+            assert!(moves.is_some(), "{} did not reach the exit", strategy);
+        }
+    }
+
+    #[test]
+    fn test_tremaux_terminates_on_braided_maze() {
+        // Sans sortie, Trémaux traverse chacun des 10 passages exactement deux fois puis revient au départ.
+        let maze: Vec<String> = BRAIDED_MAZE.iter().map(|line| line.replace('G', " ")).collect();
+        let maze: Vec<&str> = maze.iter().map(String::as_str).collect();
+        let mut navigator = Navigator::with_strategy(ExplorationStrategy::Tremaux);
+        let (moves, traversals) = simulate(&maze, &mut navigator, (1, 1), 20);
+        assert_eq!(moves, None);
+        assert_eq!(traversals.len(), 10);
+        assert!(traversals.values().all(|&count| count == 2));
+        assert_eq!(navigator.current_position, (0, 0));
+    }
+
+    #[test]
+    fn test_tremaux_turns_back_in_dead_end() {
+        // Impasse : seul le passage derrière le joueur est ouvert.
+        let mut radar_view = [[' '; 7]; 7];
+        radar_view[2][3] = '-';
+        radar_view[3][2] = '|';
+        radar_view[3][4] = '|';
+        let mut map = GlobalMap::new(10, 10);
+        map.update_from_radar(&radar_view, (0, 0));
+        let movement_history = VecDeque::from([RelativeDirection::Front]);
+        let (visited, fails, banned, reserved) = (HashSet::new(), HashMap::new(), HashSet::new(), HashSet::new());
+        let context = NavigationContext {
+            radar_view: &radar_view,
+            position: (0, 0),
+            last_direction: Some(RelativeDirection::Front),
+            movement_history: &movement_history,
+            visited_positions: &visited,
+            fail_count: &fails,
+            banned_directions: &banned,
+            global_map: &map,
+            exit_bearing: None,
+            compass_weight: 0.0,
+            reserved_cells: &reserved,
+        };
+        let mut strategy = TremauxStrategy::new();
+        strategy.on_move((0, 1), (0, 0));
+        assert_eq!(strategy.next_move(&context), Some(RelativeDirection::Back));
+        strategy.on_move((0, 0), (0, 1));
+        assert_eq!(strategy.passage_marks((0, 0), (0, 1)), 2);
+        // Un déplacement refusé n'est pas compté.
+        strategy.on_move_failure((0, 0), (0, 1));
+        assert_eq!(strategy.passage_marks((0, 0), (0, 1)), 1);
+    }
+
+    #[test]
+    fn test_greedy_tries_unknown_passage_before_giving_up() {
+        // Seul le passage de devant n'est pas un mur, mais il est inconnu du radar.
+        let mut radar_view = [['#'; 7]; 7];
+        radar_view[3][3] = ' ';
+        radar_view[3][2] = '|';
+        radar_view[3][4] = '|';
+        radar_view[4][3] = '-';
+        let mut navigator = Navigator::with_strategy(ExplorationStrategy::Greedy);
+        assert_eq!(navigator.choose_next_move(&radar_view), RelativeDirection::Front);
+    }
+
+    #[test]
+    fn test_wall_followers_prefer_their_side() {
+        let radar_view = [[' '; 7]; 7];
+        let mut left = Navigator::with_strategy(ExplorationStrategy::LeftHand);
+        assert_eq!(left.choose_next_move(&radar_view), RelativeDirection::Left);
+        let mut right = Navigator::with_strategy(ExplorationStrategy::RightHand);
+        assert_eq!(right.choose_next_move(&radar_view), RelativeDirection::Right);
+    }
+
+    #[test]
+    fn test_pledge_counts_turns() {
+        let mut strategy = PledgeStrategy::new();
+        strategy.main_direction = Some(RelativeDirection::Front);
+        // Obstacle devant : le joueur tourne à droite (+1) puis longe le mur.
+        strategy.pending_turn = Some((RelativeDirection::Right, 1));
+        strategy.on_move((0, 0), (1, 0));
+        assert_eq!(strategy.turn_count(), 1);
+        // Virage à gauche : le compteur revient à zéro, la direction principale est reprise.
+        strategy.on_move((1, 0), (1, -1));
+        assert_eq!(strategy.turn_count(), 0);
+        // Un déplacement refusé restaure l'état précédent.
+        strategy.on_move_failure((1, 0), (1, -1));
+        assert_eq!(strategy.turn_count(), 1);
+    }
+
+    #[test]
+    fn test_rotate_and_signed_turn() {
+        assert_eq!(rotate(RelativeDirection::Front, 1), RelativeDirection::Right);
+        assert_eq!(rotate(RelativeDirection::Left, 1), RelativeDirection::Front);
+        assert_eq!(rotate(RelativeDirection::Front, 3), RelativeDirection::Left);
+        assert_eq!(signed_turn(RelativeDirection::Front, RelativeDirection::Left), -1);
+        assert_eq!(signed_turn(RelativeDirection::Left, RelativeDirection::Right), 2);
+    }
+}
@@ -0,0 +1,696 @@
+use crate::global_map::{GlobalMap, Knowledge, WALLS};
+use crate::pathfinding::find_path;
+use std::collections::{HashMap, HashSet, VecDeque};
+use std::sync::{Arc, Mutex, MutexGuard};
+
+/// Nombre maximal d'observations conservées pour un joueur non ancré : au-delà, les plus anciennes sont oubliées.
+const MAX_PENDING_OBSERVATIONS: usize = 128;
+
+/// Nombre minimal de passages observés à la fois par un joueur non ancré et par l'équipe pour l'ancrer
+/// par recoupement de leurs cartes (l'équivalent d'une vue radar complète).
+const MIN_LANDMARK_PASSAGES: usize = 24;
+
+/// Observation d'un joueur, exprimée dans son propre repère, en attente d'ancrage.
+enum Observation {
+    Radar([[char; 7]; 7], (i32, i32)),
+    Blocked((i32, i32), (i32, i32)),
+    Goal((i32, i32)),
+}
+
+/// Demande de secours d'un joueur en détresse (défi `SOS`).
+struct Rescue {
+    /// Cellule du joueur en détresse, dans le repère de l'équipe.
+    position: (i32, i32),
+    /// Coéquipier chargé du secours, une fois désigné.
+    rescuer: Option<String>,
+}
+
+/// État partagé d'une équipe.
+struct TeamState {
+    /// Joueurs inscrits, dans l'ordre d'inscription (l'identifiant d'un joueur est son rang, à partir de 1).
+    members: Vec<String>,
+    /// Carte de l'équipe, dans le repère du premier joueur inscrit.
+    map: GlobalMap,
+    /// Décalage de chaque joueur ancré : `cellule équipe = cellule locale + décalage`.
+    anchors: HashMap<String, (i32, i32)>,
+    /// Observations des joueurs non encore ancrés (au plus `MAX_PENDING_OBSERVATIONS` par joueur).
+    pending: HashMap<String, VecDeque<Observation>>,
+    /// Frontière visée par chaque joueur, dans le repère de l'équipe.
+    reservations: HashMap<String, (i32, i32)>,
+    /// Dernière position connue de chaque joueur ancré, dans le repère de l'équipe.
+    positions: HashMap<String, (i32, i32)>,
+    /// Demandes de secours en cours, par joueur en détresse.
+    rescues: HashMap<String, Rescue>,
+    /// Dernier secret reçu (indice `Secret`) par chaque joueur, par identifiant.
+    secrets: HashMap<u32, u64>,
+}
+
+impl TeamState {
+    /// Intègre une observation d'un joueur ancré dans la carte de l'équipe.
+    fn apply(&mut self, offset: (i32, i32), observation: &Observation) {
+        let shift = |(x, y): (i32, i32)| (x + offset.0, y + offset.1);
+        match *observation {
+            Observation::Radar(ref radar_view, position) => self.map.update_from_radar(radar_view, shift(position)),
+            Observation::Blocked(from, to) => self.map.block_passage(shift(from), shift(to)),
+            Observation::Goal(cell) => {
+                if self.map.goal().is_none() {
+                    self.map.set_goal(shift(cell));
+                }
+            }
+        }
+    }
+
+    /// Ancre un joueur avec le décalage donné et rejoue ses observations en attente.
+    fn anchor(&mut self, player: &str, offset: (i32, i32)) {
+        if self.anchors.contains_key(player) {
+            return;
+        }
+        crate::log!("[TeamMap] Player {} anchored with offset {:?}", player, offset);
+        self.anchors.insert(player.to_string(), offset);
+        for observation in self.pending.remove(player).unwrap_or_default() {
+            self.apply(offset, &observation);
+            if let Observation::Radar(_, (x, y)) = observation {
+                self.positions.insert(player.to_string(), (x + offset.0, y + offset.1));
+            }
+        }
+        self.anchor_on_goal();
+    }
+
+    /// Ancre les joueurs en attente qui ont aperçu la sortie, si celle-ci est connue de l'équipe.
+    fn anchor_on_goal(&mut self) {
+        let Some(team_goal) = self.map.goal() else {
+            return;
+        };
+        let anchored: Vec<(String, (i32, i32))> = self
+            .pending
+            .iter()
+            .filter_map(|(player, observations)| {
+                observations.iter().find_map(|observation| match *observation {
+                    Observation::Goal((x, y)) => Some((player.clone(), (team_goal.0 - x, team_goal.1 - y))),
+                    _ => None,
+                })
+            })
+            .collect();
+        for (player, offset) in anchored {
+            self.anchor(&player, offset);
+        }
+    }
+
+    /// Ancre un joueur en attente si ses vues radar se superposent sans contradiction à la carte de l'équipe.
+    ///
+    /// La recherche n'est faite qu'à la réception d'une nouvelle vue radar du joueur : c'est elle qui fixe
+    /// les décalages candidats, ce qui en limite le coût alors que l'équipe est verrouillée.
+    fn anchor_on_landmarks(&mut self, player: &str) {
+        let Some(offset) = self.pending.get(player).and_then(|observations| self.landmark_offset(observations)) else {
+            return;
+        };
+        self.anchor(player, offset);
+    }
+
+    /// Cherche le décalage qui superpose les vues radar d'un joueur non ancré à la carte de l'équipe.
+    ///
+    /// Les décalages candidats placent la dernière position du joueur sur une cellule explorée par l'équipe, et
+    /// sont d'abord vérifiés avec sa dernière vue radar seule, avant de l'être avec toutes ses observations.
+    /// Le décalage n'est retenu que s'il est le seul à partager au moins `MIN_LANDMARK_PASSAGES` passages
+    /// avec la carte sans aucune contradiction : un couloir trop banal laisse le joueur en attente.
+    fn landmark_offset(&self, observations: &VecDeque<Observation>) -> Option<(i32, i32)> {
+        let newest = observations.iter().rev().find(|observation| matches!(observation, Observation::Radar(..)))?;
+        let Observation::Radar(_, position) = *newest else {
+            return None;
+        };
+        let bounds = self.map.explored_bounds()?;
+        let mut found = None;
+        for x in bounds.min_x..=bounds.max_x {
+            for y in bounds.min_y..=bounds.max_y {
+                if !self.map.is_cell_explored((x, y)) {
+                    continue;
+                }
+                let offset = (x - position.0, y - position.1);
+                if self.shared_passages([newest], offset).is_none() {
+                    continue;
+                }
+                if self.shared_passages(observations, offset).is_some_and(|shared| shared >= MIN_LANDMARK_PASSAGES) {
+                    if found.is_some() {
+                        return None;
+                    }
+                    found = Some(offset);
+                }
+            }
+        }
+        found
+    }
+
+    /// Nombre de passages connus de l'équipe que les vues radar du joueur confirment avec ce décalage,
+    /// ou `None` si une vue contredit la carte de l'équipe.
+    fn shared_passages<'a>(
+        &self,
+        observations: impl IntoIterator<Item = &'a Observation>,
+        offset: (i32, i32),
+    ) -> Option<usize> {
+        let mut shared = HashSet::new();
+        for observation in observations {
+            let Observation::Radar(ref radar_view, (x, y)) = *observation else {
+                continue;
+            };
+            let (center_x, center_y) = self.map.grid_position((x + offset.0, y + offset.1));
+            for (row, line) in radar_view.iter().enumerate() {
+                for (column, &content) in line.iter().enumerate() {
+                    if content == '#' {
+                        continue;
+                    }
+                    let (grid_x, grid_y) = (center_x + column as i32 - 3, center_y + row as i32 - 3);
+                    let seen = if WALLS.contains(&content) { Knowledge::Wall } else { Knowledge::Open };
+                    match self.map.knowledge(grid_x, grid_y) {
+                        Knowledge::Unknown => {}
+                        known if known != seen => return None,
+                        // Les coins sont toujours murés : seuls les passages distinguent deux endroits.
+                        _ if (row + column) % 2 == 1 => {
+                            shared.insert((grid_x, grid_y));
+                        }
+                        _ => {}
+                    }
+                }
+            }
+        }
+        Some(shared.len())
+    }
+
+    /// Enregistre une observation : appliquée immédiatement si le joueur est ancré, mise en attente sinon.
+    fn observe(&mut self, player: &str, observation: Observation) {
+        let is_goal = matches!(observation, Observation::Goal(_));
+        let mut new_pending_radar = false;
+        match self.anchors.get(player).copied() {
+            Some(offset) => {
+                self.apply(offset, &observation);
+                if let Observation::Radar(_, (x, y)) = observation {
+                    self.positions.insert(player.to_string(), (x + offset.0, y + offset.1));
+                }
+            }
+            None => {
+                let pending = self.pending.entry(player.to_string()).or_default();
+                if pending.len() == MAX_PENDING_OBSERVATIONS {
+                    pending.pop_front();
+                }
+                new_pending_radar = matches!(observation, Observation::Radar(..));
+                pending.push_back(observation);
+            }
+        }
+        if is_goal {
+            self.anchor_on_goal();
+        }
+        if new_pending_radar {
+            self.anchor_on_landmarks(player);
+        }
+    }
+
+    /// Désigne un sauveteur pour chaque demande de secours qui n'en a pas encore.
+    ///
+    /// Le sauveteur est le coéquipier disponible (ni en détresse, ni déjà sauveteur) le plus proche
+    /// par un chemin connu de la carte de l'équipe ; à défaut de chemin, le plus proche à vol d'oiseau.
+    fn assign_rescuers(&mut self) {
+        let mut waiting: Vec<String> = self
+            .rescues
+            .iter()
+            .filter(|(_, rescue)| rescue.rescuer.is_none())
+            .map(|(player, _)| player.clone())
+            .collect();
+        waiting.sort();
+        for distressed in waiting {
+            let target = self.rescues[&distressed].position;
+            let busy: HashSet<&String> = self
+                .rescues
+                .iter()
+                .flat_map(|(player, rescue)| std::iter::once(player).chain(rescue.rescuer.as_ref()))
+                .collect();
+            let rescuer = self
+                .positions
+                .iter()
+                .filter(|(player, _)| !busy.contains(player))
+                .min_by_key(|&(player, &position)| {
+                    let path = find_path(&self.map, position, |cell| cell == target);
+                    let manhattan = ((position.0 - target.0).abs() + (position.1 - target.1).abs()) as usize;
+                    (path.is_none(), path.map_or(manhattan, |path| path.len()), player.clone())
+                })
+                .map(|(player, _)| player.clone());
+            if let Some(rescuer) = rescuer {
+                crate::log!("[TeamMap] {} will rescue {} at {:?}", rescuer, distressed, target);
+                if let Some(rescue) = self.rescues.get_mut(&distressed) {
+                    rescue.rescuer = Some(rescuer);
+                }
+            }
+        }
+    }
+}
+
+/// **Carte partagée par les joueurs d'une même équipe**.
+///
+/// Chaque joueur observe le labyrinthe dans son propre repère (sa position de départ est `(0, 0)`).
+/// Le premier joueur inscrit définit le repère de l'équipe ; les autres y sont ancrés dès qu'ils
+/// partagent un repère commun avec un joueur ancré :
+/// - un endroit déjà exploré par l'équipe, reconnu lorsque leurs vues radar s'y superposent sans
+///   ambiguïté ([`TeamMember::observe`]) ;
+/// - la sortie, aperçue par chacun d'eux ([`TeamMember::report_goal`]) ;
+/// - une rencontre, les deux joueurs se trouvant sur la même cellule ([`TeamMember::report_meeting`]).
+///
+/// Jusqu'à leur ancrage, les dernières observations d'un joueur sont conservées puis rejouées dans la carte commune.
+/// Chaque joueur peut réserver la frontière qu'il vise afin que ses coéquipiers en choisissent une autre,
+/// et partage le dernier secret qu'il a reçu pour le défi `SecretSumModulo` ([`TeamMember::share_secret`]).
+///
+/// Un joueur en détresse (défi `SOS`) diffuse sa position ([`TeamMember::request_rescue`]) : le coéquipier
+/// le plus proche est chargé d'aller le chercher ([`TeamMember::rescue_target`]) puis signale la fin du
+/// secours en l'atteignant ([`TeamMember::complete_rescue`]).
+///
+/// La carte se clone à moindre coût : tous les clones partagent le même état.
+#[derive(Clone)]
+pub struct TeamMap {
+    state: Arc<Mutex<TeamState>>,
+}
+
+impl TeamMap {
+    /// Crée une carte d'équipe vide.
+    pub fn new() -> Self {
+        Self {
+            state: Arc::new(Mutex::new(TeamState {
+                members: Vec::new(),
+                map: GlobalMap::default(),
+                anchors: HashMap::new(),
+                pending: HashMap::new(),
+                reservations: HashMap::new(),
+                positions: HashMap::new(),
+                rescues: HashMap::new(),
+                secrets: HashMap::new(),
+            })),
+        }
+    }
+
+    /// Inscrit un joueur et retourne son accès à la carte de l'équipe.
+    ///
+    /// Le premier joueur inscrit est ancré d'office : son repère devient celui de l'équipe.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use maze_engine::team_map::TeamMap;
+    ///
+    /// let team = TeamMap::new();
+    /// let alice = team.join("alice");
+    /// let bob = team.join("bob");
+    /// assert!(alice.is_anchored());
+    /// assert!(!bob.is_anchored());
+    /// ```
+    pub fn join(&self, player: &str) -> TeamMember {
+        let mut state = self.lock();
+        if state.anchors.is_empty() {
+            state.anchor(player, (0, 0));
+        }
+        let id = match state.members.iter().position(|member| member == player) {
+            Some(index) => index as u32 + 1,
+            None => {
+                state.members.push(player.to_string());
+                state.members.len() as u32
+            }
+        };
+        TeamMember {
+            team: self.clone(),
+            player: player.to_string(),
+            id,
+        }
+    }
+
+    /// Retourne la sortie, si elle est connue de l'équipe.
+    pub fn goal(&self) -> Option<(i32, i32)> {
+        self.lock().map.goal()
+    }
+
+    /// Affiche la carte de l'équipe dans la console.
+    pub fn print_map(&self) {
+        self.lock().map.print_map();
+    }
+
+    fn lock(&self) -> MutexGuard<'_, TeamState> {
+        // Un joueur ayant paniqué ne doit pas priver ses coéquipiers de la carte.
+        self.state.lock().unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+impl Default for TeamMap {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Accès d'un joueur à la carte de son équipe.
+///
+/// Toutes les cellules manipulées sont exprimées dans le repère du joueur.
+pub struct TeamMember {
+    team: TeamMap,
+    player: String,
+    id: u32,
+}
+
+impl TeamMember {
+    /// Nom du joueur.
+    pub fn player(&self) -> &str {
+        &self.player
+    }
+
+    /// Identifiant du joueur dans l'équipe : son rang d'inscription, à partir de 1.
+    pub fn id(&self) -> u32 {
+        self.id
+    }
+
+    /// Indique si le joueur est ancré dans le repère de l'équipe.
+    pub fn is_anchored(&self) -> bool {
+        self.team.lock().anchors.contains_key(&self.player)
+    }
+
+    /// Partage une vue radar reçue par le joueur sur la cellule `position`.
+    ///
+    /// Un joueur non ancré l'est dès que ses vues radar recoupent sans ambiguïté la carte de l'équipe.
+    pub fn observe(&self, radar_view: &[[char; 7]; 7], position: (i32, i32)) {
+        self.team.lock().observe(&self.player, Observation::Radar(*radar_view, position));
+    }
+
+    /// Partage un mur découvert entre deux cellules adjacentes.
+    pub fn block_passage(&self, from: (i32, i32), to: (i32, i32)) {
+        self.team.lock().observe(&self.player, Observation::Blocked(from, to));
+    }
+
+    /// Partage la position de la sortie. La sortie sert de repère commun pour ancrer le joueur.
+    pub fn report_goal(&self, cell: (i32, i32)) {
+        self.team.lock().observe(&self.player, Observation::Goal(cell));
+    }
+
+    /// Signale que le joueur, en `position`, se trouve sur la même cellule que `other`, en `other_position`
+    /// (chaque position étant exprimée dans le repère de son joueur).
+    ///
+    /// Si l'un des deux joueurs est ancré, l'autre l'est à son tour.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use maze_engine::team_map::TeamMap;
+    ///
+    /// let team = TeamMap::new();
+    /// let _alice = team.join("alice");
+    /// let bob = team.join("bob");
+    /// bob.report_meeting((0, 2), "alice", (3, 1));
+    /// assert!(bob.is_anchored());
+    /// ```
+    pub fn report_meeting(&self, position: (i32, i32), other: &str, other_position: (i32, i32)) {
+        let mut state = self.team.lock();
+        let own_offset = state.anchors.get(&self.player).copied();
+        let other_offset = state.anchors.get(other).copied();
+        match (own_offset, other_offset) {
+            (None, Some((dx, dy))) => {
+                let offset = (other_position.0 + dx - position.0, other_position.1 + dy - position.1);
+                state.anchor(&self.player, offset);
+            }
+            (Some((dx, dy)), None) => {
+                let offset = (position.0 + dx - other_position.0, position.1 + dy - other_position.1);
+                state.anchor(other, offset);
+            }
+            _ => {}
+        }
+    }
+
+    /// Partage la taille du labyrinthe reçue via l'indice `GridSize`.
+    pub fn set_grid_size(&self, columns: u32, rows: u32) {
+        self.team.lock().map.set_grid_size(columns, rows);
+    }
+
+    /// Recopie dans la carte locale du joueur ce que ses coéquipiers ont découvert.
+    ///
+    /// Sans effet tant que le joueur n'est pas ancré.
+    pub fn sync(&self, local_map: &mut GlobalMap) {
+        let state = self.team.lock();
+        if let Some(&offset) = state.anchors.get(&self.player) {
+            local_map.merge_from(&state.map, offset);
+        }
+    }
+
+    /// Réserve la frontière visée par le joueur, en remplacement de sa réservation précédente.
+    pub fn reserve(&self, cell: (i32, i32)) {
+        let mut state = self.team.lock();
+        if let Some(&(dx, dy)) = state.anchors.get(&self.player) {
+            state.reservations.insert(self.player.clone(), (cell.0 + dx, cell.1 + dy));
+        }
+    }
+
+    /// Diffuse la position du joueur, en détresse sur la cellule `position`, et désigne son sauveteur.
+    ///
+    /// # Retourne
+    ///
+    /// `false` si le joueur n'est pas encore ancré : ses coéquipiers ne peuvent alors pas le localiser.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use maze_engine::team_map::TeamMap;
+    ///
+    /// let team = TeamMap::new();
+    /// let alice = team.join("alice");
+    /// let bob = team.join("bob");
+    /// bob.report_meeting((0, 0), "alice", (0, 0));
+    /// bob.observe(&[[' '; 7]; 7], (1, 0));
+    /// assert!(alice.request_rescue((0, 0)));
+    /// assert_eq!(bob.rescue_target(), Some((0, 0)));
+    /// ```
+    pub fn request_rescue(&self, position: (i32, i32)) -> bool {
+        let mut state = self.team.lock();
+        let Some(&(dx, dy)) = state.anchors.get(&self.player) else {
+            crate::log!("[TeamMap] {} needs help but is not anchored yet", self.player);
+            return false;
+        };
+        let position = (position.0 + dx, position.1 + dy);
+        crate::log!("[TeamMap] {} requests a rescue at {:?}", self.player, position);
+        state.positions.insert(self.player.clone(), position);
+        state.rescues.insert(self.player.clone(), Rescue { position, rescuer: None });
+        state.assign_rescuers();
+        true
+    }
+
+    /// Indique si le joueur attend encore d'être secouru.
+    pub fn awaiting_rescue(&self) -> bool {
+        self.team.lock().rescues.contains_key(&self.player)
+    }
+
+    /// Retourne la cellule du coéquipier que le joueur est chargé de secourir, s'il y en a un.
+    pub fn rescue_target(&self) -> Option<(i32, i32)> {
+        let mut state = self.team.lock();
+        let &(dx, dy) = state.anchors.get(&self.player)?;
+        // Un coéquipier ancré depuis la demande peut désormais être désigné.
+        state.assign_rescuers();
+        state
+            .rescues
+            .values()
+            .find(|rescue| rescue.rescuer.as_deref() == Some(self.player.as_str()))
+            .map(|rescue| (rescue.position.0 - dx, rescue.position.1 - dy))
+    }
+
+    /// Signale que le joueur a rejoint le coéquipier qu'il devait secourir.
+    ///
+    /// # Retourne
+    ///
+    /// Le nom du joueur secouru.
+    pub fn complete_rescue(&self) -> Option<String> {
+        let mut state = self.team.lock();
+        let distressed = state
+            .rescues
+            .iter()
+            .find(|(_, rescue)| rescue.rescuer.as_deref() == Some(self.player.as_str()))
+            .map(|(player, _)| player.clone())?;
+        state.rescues.remove(&distressed);
+        crate::log!("[TeamMap] {} rescued {}", self.player, distressed);
+        Some(distressed)
+    }
+
+    /// Partage le dernier secret reçu par le joueur, qui remplace le précédent.
+    pub fn share_secret(&self, secret: u64) {
+        self.team.lock().secrets.insert(self.id, secret);
+    }
+
+    /// Retourne le dernier secret connu de chaque joueur de l'équipe, par identifiant.
+    ///
+    /// Les joueurs n'ayant encore reçu aucun secret sont absents.
+    ///
+    /// # Exemple
+    ///
+    /// ```
+    /// use maze_engine::team_map::TeamMap;
+    ///
+    /// let team = TeamMap::new();
+    /// let alice = team.join("alice");
+    /// let bob = team.join("bob");
+    /// alice.share_secret(12);
+    /// bob.share_secret(30);
+    /// bob.share_secret(31);
+    /// assert_eq!(alice.secrets().get(&bob.id()), Some(&31));
+    /// assert_eq!(alice.secrets().len(), 2);
+    /// ```
+    pub fn secrets(&self) -> HashMap<u32, u64> {
+        self.team.lock().secrets.clone()
+    }
+
+    /// Dernière position connue de chaque coéquipier ancré, avec son identifiant, dans le repère du joueur.
+    ///
+    /// Vide tant que le joueur n'est pas ancré.
+    pub fn teammate_positions(&self) -> Vec<(u32, (i32, i32))> {
+        let state = self.team.lock();
+        let Some(&(dx, dy)) = state.anchors.get(&self.player) else {
+            return Vec::new();
+        };
+        state
+            .members
+            .iter()
+            .enumerate()
+            .filter(|(_, member)| **member != self.player)
+            .filter_map(|(index, member)| {
+                let &(x, y) = state.positions.get(member)?;
+                Some((index as u32 + 1, (x - dx, y - dy)))
+            })
+            .collect()
+    }
+
+    /// Retourne les frontières réservées par les coéquipiers et encore inexplorées.
+    pub fn reserved_by_teammates(&self) -> HashSet<(i32, i32)> {
+        let state = self.team.lock();
+        let Some(&(dx, dy)) = state.anchors.get(&self.player) else {
+            return HashSet::new();
+        };
+        state
+            .reservations
+            .iter()
+            .filter(|&(player, &cell)| *player != self.player && !state.map.is_cell_explored(cell))
+            .map(|(_, &(x, y))| (x - dx, y - dy))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_goal_anchors_pending_player() {
+        let team = TeamMap::new();
+        let alice = team.join("alice");
+        let bob = team.join("bob");
+
+        // Bob observe avant d'être ancré : ses observations sont mises en attente.
+        bob.observe(&[[' '; 7]; 7], (0, 0));
+        bob.report_goal((1, 0));
+        assert!(!bob.is_anchored());
+
+        // Alice voit la même sortie en (3, 2) : Bob est ancré avec un décalage (2, 2).
+        alice.report_goal((3, 2));
+        assert!(bob.is_anchored());
+        let mut alice_map = GlobalMap::new(10, 10);
+        alice.sync(&mut alice_map);
+        assert!(alice_map.is_passage_open((2, 2), (1, 2)));
+        assert_eq!(alice_map.goal(), Some((3, 2)));
+    }
+
+    #[test]
+    fn test_pending_observations_are_bounded() {
+        let team = TeamMap::new();
+        let _alice = team.join("alice");
+        let bob = team.join("bob");
+        for x in 0..2 * MAX_PENDING_OBSERVATIONS as i32 {
+            bob.block_passage((x, 0), (x + 1, 0));
+        }
+        assert_eq!(team.lock().pending["bob"].len(), MAX_PENDING_OBSERVATIONS);
+    }
+
+    #[test]
+    fn test_sync_translates_into_local_frame() {
+        let team = TeamMap::new();
+        let alice = team.join("alice");
+        let bob = team.join("bob");
+        bob.report_meeting((0, 0), "alice", (2, 0));
+
+        alice.observe(&[[' '; 7]; 7], (2, 0));
+        let mut bob_map = GlobalMap::new(10, 10);
+        bob.sync(&mut bob_map);
+        assert!(bob_map.is_cell_explored((1, 1)));
+        assert!(!bob_map.is_cell_explored((2, 0)));
+    }
+
+    #[test]
+    fn test_reservations_are_shared_between_teammates() {
+        let team = TeamMap::new();
+        let alice = team.join("alice");
+        let bob = team.join("bob");
+        alice.reserve((3, 0));
+        // Bob n'est pas ancré : il ne peut pas interpréter la réservation.
+        assert!(bob.reserved_by_teammates().is_empty());
+
+        bob.report_meeting((0, 0), "alice", (1, 0));
+        assert_eq!(bob.reserved_by_teammates(), HashSet::from([(2, 0)]));
+        assert!(alice.reserved_by_teammates().is_empty());
+
+        // Une frontière explorée n'est plus réservée.
+        alice.observe(&[[' '; 7]; 7], (2, 0));
+        assert!(bob.reserved_by_teammates().is_empty());
+    }
+
+    #[test]
+    fn test_teammate_positions_in_local_frame() {
+        let team = TeamMap::new();
+        let alice = team.join("alice");
+        let bob = team.join("bob");
+        let _carol = team.join("carol");
+        alice.observe(&[[' '; 7]; 7], (2, 0));
+        assert!(bob.teammate_positions().is_empty());
+
+        bob.report_meeting((0, 0), "alice", (1, 0));
+        assert_eq!(bob.teammate_positions(), vec![(1, (1, 0))]);
+        assert_eq!(alice.teammate_positions(), vec![]);
+    }
+
+    #[test]
+    fn test_member_ids_follow_registration_order() {
+        let team = TeamMap::new();
+        assert_eq!(team.join("alice").id(), 1);
+        assert_eq!(team.join("bob").id(), 2);
+        assert_eq!(team.join("alice").id(), 1);
+    }
+
+    #[test]
+    fn test_nearest_teammate_rescues() {
+        let team = TeamMap::new();
+        let alice = team.join("alice");
+        let bob = team.join("bob");
+        let carol = team.join("carol");
+        bob.report_meeting((0, 0), "alice", (0, 0));
+        carol.report_meeting((0, 0), "alice", (0, 0));
+        alice.observe(&[[' '; 7]; 7], (0, 0));
+        bob.observe(&[[' '; 7]; 7], (1, 0));
+        carol.observe(&[[' '; 7]; 7], (4, 0));
+
+        assert!(alice.request_rescue((0, 0)));
+        assert!(alice.awaiting_rescue());
+        assert_eq!(bob.rescue_target(), Some((0, 0)));
+        assert_eq!(carol.rescue_target(), None);
+        assert_eq!(alice.rescue_target(), None);
+
+        assert_eq!(carol.complete_rescue(), None);
+        assert_eq!(bob.complete_rescue(), Some("alice".to_string()));
+        assert!(!alice.awaiting_rescue());
+        assert_eq!(bob.rescue_target(), None);
+    }
+
+    #[test]
+    fn test_rescuer_assigned_once_anchored() {
+        let team = TeamMap::new();
+        let alice = team.join("alice");
+        let bob = team.join("bob");
+        bob.observe(&[[' '; 7]; 7], (0, 0));
+        assert!(alice.request_rescue((0, 0)));
+        // Bob n'est pas ancré : il ne peut pas être désigné.
+        assert_eq!(bob.rescue_target(), None);
+
+        bob.report_meeting((2, 0), "alice", (1, 0));
+        assert_eq!(bob.rescue_target(), Some((1, 0)));
+    }
+}
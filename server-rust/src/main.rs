@@ -3,12 +3,20 @@ mod structure;
 use std::sync::{Arc, Mutex};
 use std::net::{TcpListener, TcpStream};
 use std::io::{Read, Write};
+use std::path::PathBuf;
+use std::str::FromStr;
 use std::thread;
 use commun::structs::{Command};
-use structure::{team::TeamManager, player::Player, challenge::ChallengePosition, command::CommandFunction};
+use commun::{thread_rng, Rng};
+use structure::{team::GamePhase, connection::ConnectionState, challenge_kind::ChallengeRegistry, command::CommandFunction, game::{GameSettings, Lobby}, spawn::SpawnPolicy};
 use tracing::{info, error};
 use commun::serde_json;
 
+/// Largeur et hauteur par défaut des labyrinthes, en cellules.
+const DEFAULT_MAZE_SIZE: usize = 5;
+/// Nombre d'équipes attendues par défaut dans chaque partie.
+const DEFAULT_TEAMS_PER_GAME: usize = 1;
+
 fn send_to_client(mut stream: &TcpStream, message: String) -> std::io::Result<()> {
     let message_bytes = message.as_bytes();
     let n = message.len() as u32;
@@ -22,8 +30,10 @@ fn send_to_client(mut stream: &TcpStream, message: String) -> std::io::Result<()
 }
 
 
-fn handle_client(mut stream: TcpStream, team_manager: Arc<Mutex<TeamManager>>, player: Arc<Mutex<Player>>, challenge: Arc<Mutex<ChallengePosition>>) {
+fn handle_client(mut stream: TcpStream, lobby: Arc<Mutex<Lobby>>) {
     info!("New connection from {}", stream.peer_addr().unwrap());
+    // Phase du protocole pour cette connexion : équipe ou joueur inscrit.
+    let mut state = ConnectionState::default();
 
     loop {
         let mut size_buffer = [0_u8; 4];
@@ -46,7 +56,12 @@ fn handle_client(mut stream: TcpStream, team_manager: Arc<Mutex<TeamManager>>, p
         match serde_json::from_str::<Command>(&received_data) {
             Ok(command) => {
                 info!("Parsed command: {:?}", command);
-                Command::process(command, stream.try_clone().unwrap(), team_manager.clone(), player.clone(), challenge.clone());
+                Command::process(command, stream.try_clone().unwrap(), lobby.clone(), &mut state);
+                // Une fois la partie finie, la fermeture de la connexion l'annonce au client.
+                if is_game_finished(&lobby, &state) {
+                    info!("Game finished - Closing connection");
+                    break;
+                }
             }
             Err(e) => {
                 error!("Error parsing JSON: {} - Closing connection", e);
@@ -54,33 +69,64 @@ fn handle_client(mut stream: TcpStream, team_manager: Arc<Mutex<TeamManager>>, p
             }
         }
     }
-}
 
-fn inner_main() -> std::io::Result<()> {
-    let listener = TcpListener::bind("127.0.0.1:8778")?;
-    info!("Server listening on 127.0.0.1:8778");
+    // Un joueur qui ferme sa connexion avant d'être sorti quitte la partie.
+    if let ConnectionState::Player(key) = &state {
+        let game = lobby.lock().unwrap().game(&key.game);
+        if let Some(game) = game {
+            game.lock().unwrap().disconnect_player(key);
+        }
+    }
+}
 
-    let team_manager = Arc::new(Mutex::new(TeamManager::new()));
-    let player = Arc::new(Mutex::new(Player::new("Player1".to_string())));
-    let challenge = Arc::new(Mutex::new(ChallengePosition::new(structure::maze::Point { x: 3, y: 5 })));
+fn is_game_finished(lobby: &Arc<Mutex<Lobby>>, state: &ConnectionState) -> bool {
+    let Some(name) = state.game() else {
+        return false;
+    };
+    let game = lobby.lock().unwrap().game(name);
+    game.is_some_and(|game| game.lock().unwrap().phase() == GamePhase::Finished)
+}
 
-    let (pos_x, pos_y) = {
-        let player_lock = player.lock().unwrap();
-        let pos = player_lock.get_position();
-        (pos.x, pos.y)
+fn parse_args(args: impl Iterator<Item = String>) -> Result<GameSettings, String> {
+    let mut settings = GameSettings {
+        seed: thread_rng().gen(),
+        maze_size: DEFAULT_MAZE_SIZE,
+        teams_per_game: DEFAULT_TEAMS_PER_GAME,
+        spawn: SpawnPolicy::Random,
+        results_dir: PathBuf::from("."),
     };
+    let mut args = args;
+    while let Some(arg) = args.next() {
+        let value = args.next().ok_or(format!("{} attend une valeur", arg));
+        match arg.as_str() {
+            "--seed" => settings.seed = parse_number(&arg, value?)?,
+            "--maze-size" => settings.maze_size = parse_number(&arg, value?)?,
+            "--teams-per-game" => settings.teams_per_game = parse_number::<usize>(&arg, value?)?.max(1),
+            "--spawn" => settings.spawn = value?.parse()?,
+            "--results-dir" => settings.results_dir = PathBuf::from(value?),
+            _ => return Err(format!("Argument inconnu : {}", arg)),
+        }
+    }
+    Ok(settings)
+}
+
+fn parse_number<T: FromStr>(name: &str, value: String) -> Result<T, String> {
+    value.parse().map_err(|_| format!("Valeur invalide pour {} : {}", name, value))
+}
+
+fn inner_main(settings: GameSettings) -> std::io::Result<()> {
+    let listener = TcpListener::bind("127.0.0.1:8778")?;
+    info!("Server listening on 127.0.0.1:8778");
 
-    info!("Player initialized at position: x={}, y={}", pos_x, pos_y);
+    let lobby = Arc::new(Mutex::new(Lobby::new(settings, ChallengeRegistry::builtin())));
 
     for stream in listener.incoming() {
         match stream {
             Ok(stream) => {
                 info!("New client connected: {}", stream.peer_addr()?);
-                let team_manager = Arc::clone(&team_manager);
-                let player = Arc::clone(&player);
-                let challenge = Arc::clone(&challenge);
+                let lobby = Arc::clone(&lobby);
                 thread::spawn(move || {
-                    handle_client(stream, team_manager, player, challenge);
+                    handle_client(stream, lobby);
                 });
             }
             Err(e) => {
@@ -96,7 +142,16 @@ fn main() {
         .with_max_level(tracing::Level::INFO)
         .init();
 
-    if let Err(err) = inner_main() {
+    let settings = match parse_args(std::env::args().skip(1)) {
+        Ok(settings) => settings,
+        Err(message) => {
+            error!("{}", message);
+            eprintln!("Usage : server-rust [--seed <graine>] [--maze-size <cellules>] [--teams-per-game <équipes>] [--spawn random|team|farthest] [--results-dir <répertoire>]");
+            std::process::exit(2);
+        }
+    };
+
+    if let Err(err) = inner_main(settings) {
         error!("Server encountered an error: {}", err);
     }
 }
\ No newline at end of file
@@ -3,65 +3,95 @@ use std::net::TcpStream;
 use commun::structs::{Action, RelativeDirection};
 use crate::send_to_client;
 use super::{
-    challenge::{check_all_condition_challenge, check_is_challenge_position, ChallengePosition},
-    maze::{check_movement_possible, Point},
+    challenge::{check_all_condition_challenge, check_is_challenge_position, check_no_pending_challenge, CHALLENGE_ABANDON_PENALTY},
+    game::Game,
+    maze::Point,
     message::action_result,
-    player::Player
+    team::PlayerKey,
 };
 use tracing::{info, warn, error};
 
 pub trait ActionFunction {
-    fn process_direction(direction: RelativeDirection, player: Arc<Mutex<Player>>, stream: TcpStream, challenge: Arc<Mutex<ChallengePosition>>);
-    fn process_challenge(answer: String, player: Arc<Mutex<Player>>, stream: TcpStream, challenge: Arc<Mutex<ChallengePosition>>);
-    fn process(action: Action, stream: TcpStream, player: Arc<Mutex<Player>>, challenge: Arc<Mutex<ChallengePosition>>);
+    fn process_direction(direction: RelativeDirection, stream: TcpStream, game: Arc<Mutex<Game>>, session: &PlayerKey);
+    fn process_challenge(answer: String, stream: TcpStream, game: Arc<Mutex<Game>>, session: &PlayerKey);
+    fn process(action: Action, stream: TcpStream, game: Arc<Mutex<Game>>, session: &PlayerKey);
 }
 
 impl ActionFunction for Action {
-    fn process_direction(direction: RelativeDirection, player: Arc<Mutex<Player>>, stream: TcpStream, challenge: Arc<Mutex<ChallengePosition>>) {
+    fn process_direction(direction: RelativeDirection, stream: TcpStream, game: Arc<Mutex<Game>>, session: &PlayerKey) {
         info!("Processing MoveTo: {:?}", direction);
 
-        let mut player = player.lock().unwrap();
-        let challenge = challenge.lock().unwrap();
+        let mut game = game.lock().unwrap();
+        let Game { maze, teams: manager, challenges: board, .. } = &mut *game;
+        let team = session.registration_token.as_str();
+        let Some(player) = manager.player_mut(session) else {
+            error!("Unknown player '{}', ignoring MoveTo", session.player);
+            return;
+        };
 
-        let message = match check_movement_possible(direction, &player) {
+        let mut exited = false;
+        let mut abandoned_challenge = false;
+        let movement = check_no_pending_challenge(player).and_then(|abandoned| {
+            abandoned_challenge = abandoned;
+            maze.check_movement_possible(direction, player)
+        });
+        let message = match movement {
             Ok(new_position) => {
+                exited = maze.is_exit(&new_position);
                 player.set_position(Point { x: new_position.x, y: new_position.y });
-                let is_challenge_position = check_is_challenge_position(&player.get_position(), &challenge);
-                if is_challenge_position {
+                let challenge = check_is_challenge_position(&player.get_position(), board, team);
+                if challenge.is_some() {
                     player.set_is_challenge_actif(true);
                     info!("Player has reached a challenge position.");
                 }
                 info!("Player moved to new position: x={}, y={}", new_position.x, new_position.y);
-                action_result(Ok((player.clone(), is_challenge_position)))
+                action_result(Ok((player.clone(), challenge)), maze)
             },
             Err(action_err) => {
                 warn!("Movement failed: {:?}", action_err);
-                action_result(Err(action_err))
+                action_result(Err(action_err), maze)
             },
         };
 
+        if abandoned_challenge {
+            manager.record_penalty(session, CHALLENGE_ABANDON_PENALTY);
+        }
+        manager.record_move(session, exited);
+        manager.update_phase(maze);
+
         if let Err(e) = send_to_client(&stream, message) {
             error!("Failed to send response to client: {}", e);
         }
     }
 
-    fn process_challenge(answer: String, player: Arc<Mutex<Player>>, stream: TcpStream, challenge: Arc<Mutex<ChallengePosition>>) {
+    fn process_challenge(answer: String, stream: TcpStream, game: Arc<Mutex<Game>>, session: &PlayerKey) {
         info!("Processing challenge solution: {}", answer);
 
-        let mut challenge = challenge.lock().unwrap();
-        let mut player = player.lock().unwrap();
+        let mut game = game.lock().unwrap();
+        let Game { maze, teams: manager, challenges: board, .. } = &mut *game;
+        let team = session.registration_token.as_str();
+        let Some(player) = manager.player_mut(session) else {
+            error!("Unknown player '{}', ignoring SolveChallenge", session.player);
+            return;
+        };
+        let position = player.get_position();
 
-        let message = match check_all_condition_challenge(answer, &challenge, &player) {
+        let message = match check_all_condition_challenge(answer, board.at(&position), player) {
             Ok(()) => {
                 player.set_is_challenge_actif(false);
-                challenge.update_challenge_statut(true);
-                let is_challenge_position = check_is_challenge_position(&player.get_position(), &challenge);
+                if let Some(challenge) = board.at_mut(&position) {
+                    challenge.mark_solved(team);
+                    challenge.get_kind().on_success(player);
+                }
+                let challenge = check_is_challenge_position(&position, board, team);
                 info!("Challenge successfully completed.");
-                action_result(Ok((player.clone(), is_challenge_position)))
+                let message = action_result(Ok((player.clone(), challenge)), maze);
+                manager.record_challenge_solved(session);
+                message
             },
             Err(action_err) => {
                 warn!("Challenge failed: {:?}", action_err);
-                action_result(Err(action_err))
+                action_result(Err(action_err), maze)
             },
         };
 
@@ -70,10 +100,10 @@ impl ActionFunction for Action {
         }
     }
 
-    fn process(action: Action, stream: TcpStream, player: Arc<Mutex<Player>>, challenge: Arc<Mutex<ChallengePosition>>) {
+    fn process(action: Action, stream: TcpStream, game: Arc<Mutex<Game>>, session: &PlayerKey) {
         match action {
-            Action::MoveTo(direction) => Self::process_direction(direction, player, stream, challenge),
-            Action::SolveChallenge { answer } => Self::process_challenge(answer, player, stream, challenge),
+            Action::MoveTo(direction) => Self::process_direction(direction, stream, game, session),
+            Action::SolveChallenge { answer } => Self::process_challenge(answer, stream, game, session),
         };
     }
 }
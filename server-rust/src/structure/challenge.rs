@@ -1,40 +1,94 @@
-use commun::structs::ActionError;
-use super::{maze::Point, player::Player};
+use std::collections::HashSet;
+use std::sync::Arc;
+use std::time::Duration;
+use commun::structs::{ActionError, Challenge};
+use super::{challenge_kind::ChallengeKind, maze::Point, player::Player};
 use tracing::{info, warn};
 
+/// Durée au-delà de laquelle un défi non résolu est abandonné : le joueur peut de nouveau se déplacer.
+pub const CHALLENGE_TIMEOUT: Duration = Duration::from_secs(30);
+pub use commun::rules::CHALLENGE_ABANDON_PENALTY;
+
 #[derive(Clone, Debug)]
 pub struct ChallengePosition {
     position: Point,
-    answer: String,
-    is_finish: bool,
+    /// Nom du type de défi dans le registre.
+    name: String,
+    kind: Arc<dyn ChallengeKind>,
+    /// Équipes (par jeton d'inscription) ayant résolu ce défi.
+    solved_by: HashSet<String>,
 }
 
 impl ChallengePosition {
-    pub fn new(position: Point) -> Self {
-        info!("Creating new challenge at position: x={}, y={}", position.x, position.y);
+    pub fn new(position: Point, name: &str, kind: Arc<dyn ChallengeKind>) -> Self {
+        info!("Creating new challenge '{}' ({}) at position: x={}, y={}", name, kind.describe(), position.x, position.y);
         Self {
             position: Point { x: position.x, y: position.y },
-            answer: String::from("0"),
-            is_finish: false,
+            name: name.to_string(),
+            kind,
+            solved_by: HashSet::new(),
         }
     }
 
-    pub fn update_challenge_statut(&mut self, is_finish: bool) {
-        self.is_finish = is_finish;
-        info!("Challenge status updated: is_finish={}", is_finish);
+    pub fn mark_solved(&mut self, team: &str) {
+        self.solved_by.insert(team.to_string());
+        info!("Challenge '{}' at x={}, y={} solved by team {}", self.name, self.position.x, self.position.y, team);
+    }
+
+    pub fn get_challenge(&self) -> Challenge {
+        self.kind.generate()
     }
 
-    pub fn get_challenge_answer(&self) -> &str {
-        &self.answer
+    pub fn get_kind(&self) -> &dyn ChallengeKind {
+        self.kind.as_ref()
     }
 
-    pub fn get_challenge_statut(&self) -> bool {
-        self.is_finish
+    pub fn is_solved_by(&self, team: &str) -> bool {
+        self.solved_by.contains(team)
     }
 
     pub fn get_challenge_position(&self) -> &Point {
         &self.position
     }
+
+    fn is_at(&self, position: &Point) -> bool {
+        let challenge_position = self.get_challenge_position();
+        challenge_position.x == position.x && challenge_position.y == position.y
+    }
+}
+
+/// Ensemble des défis du labyrinthe, chacun avec son propre état de résolution par équipe.
+#[derive(Clone, Debug, Default)]
+pub struct ChallengeBoard {
+    challenges: Vec<ChallengePosition>,
+}
+
+impl ChallengeBoard {
+    pub fn new(challenges: Vec<ChallengePosition>) -> Self {
+        info!("Placing {} challenges in the maze", challenges.len());
+        Self { challenges }
+    }
+
+    /// Retourne le défi placé sur une cellule, s'il y en a un.
+    pub fn at(&self, position: &Point) -> Option<&ChallengePosition> {
+        self.challenges
+            .iter()
+            .find(|challenge| challenge.is_at(position))
+    }
+
+    /// Cellules occupées par les défis.
+    pub fn cells(&self) -> HashSet<(i32, i32)> {
+        self.challenges
+            .iter()
+            .map(|challenge| (challenge.position.x, challenge.position.y))
+            .collect()
+    }
+
+    pub fn at_mut(&mut self, position: &Point) -> Option<&mut ChallengePosition> {
+        self.challenges
+            .iter_mut()
+            .find(|challenge| challenge.is_at(position))
+    }
 }
 
 fn check_is_challenge_available(player: &Player) -> bool {
@@ -43,19 +97,19 @@ fn check_is_challenge_available(player: &Player) -> bool {
     available
 }
 
-fn check_answer_is_correct(challenge: &ChallengePosition, answer: &str) -> bool {
-    let correct = challenge.get_challenge_answer() == answer;
-    info!("Checking challenge answer: expected={}, received={}, correct={}", challenge.get_challenge_answer(), answer, correct);
+fn check_answer_is_correct(challenge: &ChallengePosition, answer: &str, player: &Player) -> bool {
+    let correct = challenge.get_kind().validate(answer, player);
+    info!("Checking challenge answer: challenge={}, received={}, correct={}", challenge.get_kind().describe(), answer, correct);
     correct
 }
 
-pub fn check_all_condition_challenge(answer: String, challenge: &ChallengePosition, player: &Player) -> Result<(), ActionError> {
-    if !check_is_challenge_available(player) {
+pub fn check_all_condition_challenge(answer: String, challenge: Option<&ChallengePosition>, player: &Player) -> Result<(), ActionError> {
+    let Some(challenge) = challenge.filter(|_| check_is_challenge_available(player)) else {
         warn!("Player attempted to solve a challenge without an active challenge.");
         return Err(ActionError::NoRunningChallenge);
-    }
+    };
 
-    if !check_answer_is_correct(challenge, &answer) {
+    if !check_answer_is_correct(challenge, &answer, player) {
         warn!("Player provided an incorrect challenge solution.");
         return Err(ActionError::InvalidChallengeSolution);
     }
@@ -64,11 +118,61 @@ pub fn check_all_condition_challenge(answer: String, challenge: &ChallengePositi
     Ok(())
 }
 
-pub fn check_is_challenge_position(position_player: &Point, challenge: &ChallengePosition) -> bool {
-    let position_challenge = challenge.get_challenge_position();
-    let is_finish_challenge = challenge.get_challenge_statut();
+/// Vérifie que le joueur peut se déplacer : tant qu'un défi est en cours, il doit d'abord le résoudre.
+///
+/// Retourne `Ok(true)` si le défi en cours vient d'être abandonné (après `CHALLENGE_TIMEOUT`).
+pub fn check_no_pending_challenge(player: &mut Player) -> Result<bool, ActionError> {
+    if !check_is_challenge_available(player) {
+        return Ok(false);
+    }
+    if player.get_challenge_elapsed() >= CHALLENGE_TIMEOUT {
+        warn!("Challenge not solved after {:?}, player may move again with a penalty.", CHALLENGE_TIMEOUT);
+        player.set_is_challenge_actif(false);
+        return Ok(true);
+    }
+    warn!("Player must solve the running challenge before moving.");
+    Err(ActionError::SolveChallengeFirst)
+}
+
+/// Retourne le défi à poser au joueur arrivé sur `position_player`, si l'équipe `team` ne l'a pas encore résolu.
+pub fn check_is_challenge_position(position_player: &Point, board: &ChallengeBoard, team: &str) -> Option<Challenge> {
+    let challenge = board
+        .at(position_player)
+        .filter(|challenge| !challenge.is_solved_by(team))
+        .map(|challenge| challenge.get_challenge());
+    info!("Checking if player is on a challenge position: result={:?}", challenge);
+    challenge
+}
 
-    let is_correct_position = !is_finish_challenge && position_player.x == position_challenge.x && position_player.y == position_challenge.y;
-    info!("Checking if player is on a challenge position: result={}", is_correct_position);
-    is_correct_position
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::structure::challenge_kind::SecretSumModulo;
+
+    fn board() -> ChallengeBoard {
+        ChallengeBoard::new(vec![ChallengePosition::new(Point { x: 3, y: 1 }, "secret_sum_modulo_7", Arc::new(SecretSumModulo::new(7)))])
+    }
+
+    #[test]
+    fn test_challenge_is_solved_per_team() {
+        let mut board = board();
+        let position = Point { x: 3, y: 1 };
+        let challenge = board.at_mut(&position).unwrap();
+        assert!(!challenge.is_solved_by("alpha"));
+        challenge.mark_solved("alpha");
+        assert!(challenge.is_solved_by("alpha"));
+        assert!(!challenge.is_solved_by("beta"));
+    }
+
+    #[test]
+    fn test_challenge_position_is_asked_until_solved_by_the_team() {
+        let mut board = board();
+        let position = Point { x: 3, y: 1 };
+        assert_eq!(check_is_challenge_position(&Point { x: 1, y: 1 }, &board, "alpha"), None);
+        assert_eq!(check_is_challenge_position(&position, &board, "alpha"), Some(Challenge::SecretSumModulo(7)));
+
+        board.at_mut(&position).unwrap().mark_solved("alpha");
+        assert_eq!(check_is_challenge_position(&position, &board, "alpha"), None);
+        assert_eq!(check_is_challenge_position(&position, &board, "beta"), Some(Challenge::SecretSumModulo(7)));
+    }
 }
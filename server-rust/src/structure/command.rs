@@ -1,28 +1,189 @@
 use std::sync::{Arc, Mutex};
-use std::net::TcpStream;
-use commun::structs::{Action, Command};
-use super::{action::ActionFunction, challenge::ChallengePosition, player::Player, team::*};
-use tracing::{info};
+use std::net::{Shutdown, TcpStream};
+use commun::structs::{Action, Command, RegistrationError};
+use crate::send_to_client;
+use super::{action::ActionFunction, connection::ConnectionState, game::Lobby, message::{register_team_result, subscribe_player_result}, team::*};
+use tracing::{info, warn, error};
 
 pub trait CommandFunction {
-    fn process(command: Command, stream: TcpStream, team_manager: Arc<Mutex<TeamManager>>, player: Arc<Mutex<Player>>, challenge: Arc<Mutex<ChallengePosition>>);
+    fn process(command: Command, stream: TcpStream, lobby: Arc<Mutex<Lobby>>, state: &mut ConnectionState);
 }
 
 impl CommandFunction for Command {
-    fn process(command: Command, stream: TcpStream, team_manager: Arc<Mutex<TeamManager>>, player: Arc<Mutex<Player>>, challenge: Arc<Mutex<ChallengePosition>>) {
-        match command {
-            Command::RegisterTeam { name } => {
+    fn process(command: Command, stream: TcpStream, lobby: Arc<Mutex<Lobby>>, state: &mut ConnectionState) {
+        match (command, &*state) {
+            (Command::RegisterTeam { name }, ConnectionState::Unauthenticated) => {
                 info!("Processing register_team for: {}", name);
-                TeamCommand::process(TeamCommand::Create(name), stream, team_manager);
+                // Le lobby reste verrouillé pendant l'inscription : la partie choisie ne peut pas fermer ses inscriptions entre-temps.
+                let mut lobby = lobby.lock().unwrap();
+                let game = match lobby.assign(&name) {
+                    Ok(game) => game,
+                    Err(error) => {
+                        if let Err(e) = send_to_client(&stream, register_team_result(Err(error))) {
+                            error!("Failed to send team creation response: {}", e);
+                        }
+                        return;
+                    }
+                };
+                if let Some(new_state) = TeamCommand::process(TeamCommand::Create(name), stream, game.clone()) {
+                    if let ConnectionState::Team { registration_token, .. } = &new_state {
+                        lobby.record_team(registration_token, game);
+                    }
+                    *state = new_state;
+                }
             }
-            Command::SubscribePlayer { name, registration_token } => {
+            (Command::SubscribePlayer { name, registration_token }, ConnectionState::Unauthenticated) => {
                 info!("Processing subscribe_player for: {}", name);
-                TeamCommand::process(TeamCommand::SubscribePlayer { name, registration_token }, stream, team_manager);
+                let Some(game) = lobby.lock().unwrap().game_for_token(&registration_token) else {
+                    warn!("Invalid registration token: {}", registration_token);
+                    if let Err(e) = send_to_client(&stream, subscribe_player_result(Err(RegistrationError::InvalidRegistrationToken))) {
+                        error!("Failed to send player registration response: {}", e);
+                    }
+                    return;
+                };
+                if let Some(new_state) = TeamCommand::process(TeamCommand::SubscribePlayer { name, registration_token }, stream, game) {
+                    *state = new_state;
+                }
             }
-            Command::Action(action) => {
+            (Command::Action(action), ConnectionState::Player(key)) => {
                 info!("Processing Action: {:?}", action);
-                Action::process(action, stream, player, challenge);
+                let Some(game) = lobby.lock().unwrap().game(&key.game) else {
+                    error!("Unknown game '{}', ignoring action", key.game);
+                    return;
+                };
+                {
+                    let game = game.lock().unwrap();
+                    // Hors de la partie en cours, l'action reste sans réponse : les premières vues radar
+                    // sont envoyées ensemble au début de la partie.
+                    if game.phase() != GamePhase::Running {
+                        warn!("Action received while game is {:?}, ignoring", game.phase());
+                        return;
+                    }
+                    if game.teams.has_exited(key) {
+                        warn!("Player '{}' already exited the maze, closing connection", key.player);
+                        if let Err(e) = stream.shutdown(Shutdown::Both) {
+                            error!("Failed to close connection: {}", e);
+                        }
+                        return;
+                    }
+                }
+                Action::process(action, stream, game, key);
             }
+            (command, state) => match state.reject(&command) {
+                Some(response) => {
+                    if let Err(e) = send_to_client(&stream, response) {
+                        error!("Failed to send rejection to client: {}", e);
+                    }
+                }
+                None => {
+                    warn!("Closing connection after an action without a registered player");
+                    if let Err(e) = stream.shutdown(Shutdown::Both) {
+                        error!("Failed to close connection: {}", e);
+                    }
+                }
+            },
         }
     }
-}
\ No newline at end of file
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::net::TcpListener;
+    use commun::decodage::decode_message;
+    use commun::structs::{JsonWrapper, RegisterTeamResult, RelativeDirection, SubscribePlayerResult};
+    use crate::structure::challenge_kind::ChallengeRegistry;
+    use crate::structure::game::GameSettings;
+    use crate::structure::spawn::SpawnPolicy;
+
+    /// Connexion locale : le côté serveur, puis le côté client.
+    fn connection() -> (TcpStream, TcpStream) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (server, _) = listener.accept().unwrap();
+        (server, client)
+    }
+
+    fn lobby() -> Arc<Mutex<Lobby>> {
+        let settings = GameSettings { seed: 0, maze_size: 3, teams_per_game: 1, spawn: SpawnPolicy::Random, results_dir: env::temp_dir() };
+        Arc::new(Mutex::new(Lobby::new(settings, ChallengeRegistry::builtin())))
+    }
+
+    /// Traite une commande sur une nouvelle connexion dans l'état `state`, et retourne la réponse reçue par le client
+    /// (`None` si la connexion a été fermée sans réponse).
+    fn send(command: Command, lobby: &Arc<Mutex<Lobby>>, state: &mut ConnectionState) -> Option<JsonWrapper> {
+        let (server, mut client) = connection();
+        Command::process(command, server, lobby.clone(), state);
+        decode_message(&mut client).ok()
+    }
+
+    fn register_team(name: &str) -> Command {
+        Command::RegisterTeam { name: name.to_string() }
+    }
+
+    fn subscribe_player(name: &str, registration_token: &str) -> Command {
+        Command::SubscribePlayer { name: name.to_string(), registration_token: registration_token.to_string() }
+    }
+
+    fn move_to() -> Command {
+        Command::Action(Action::MoveTo(RelativeDirection::Front))
+    }
+
+    #[test]
+    fn test_unauthenticated_connection() {
+        let lobby = lobby();
+        let mut state = ConnectionState::Unauthenticated;
+        let response = send(subscribe_player("player1", "unknown"), &lobby, &mut state);
+        assert_eq!(response, Some(JsonWrapper::SubscribePlayerResult(SubscribePlayerResult::Err(RegistrationError::InvalidRegistrationToken))));
+        assert!(matches!(state, ConnectionState::Unauthenticated));
+
+        // Une action sans joueur inscrit ferme la connexion.
+        assert_eq!(send(move_to(), &lobby, &mut state), None);
+        assert!(matches!(state, ConnectionState::Unauthenticated));
+
+        let response = send(register_team("team"), &lobby, &mut state);
+        assert!(matches!(response, Some(JsonWrapper::RegisterTeamResult(RegisterTeamResult::Ok { .. }))));
+        assert!(matches!(state, ConnectionState::Team { .. }));
+    }
+
+    #[test]
+    fn test_team_connection() {
+        let lobby = lobby();
+        let mut state = ConnectionState::Unauthenticated;
+        send(register_team("team"), &lobby, &mut state);
+        let ConnectionState::Team { registration_token, .. } = state.clone() else {
+            panic!("team not registered: {:?}", state);
+        };
+
+        let response = send(register_team("other"), &lobby, &mut state);
+        assert_eq!(response, Some(JsonWrapper::RegisterTeamResult(RegisterTeamResult::Err(RegistrationError::AlreadyRegistered))));
+        let response = send(subscribe_player("player1", &registration_token), &lobby, &mut state);
+        assert_eq!(response, Some(JsonWrapper::SubscribePlayerResult(SubscribePlayerResult::Err(RegistrationError::AlreadyRegistered))));
+        assert_eq!(send(move_to(), &lobby, &mut state), None);
+        assert!(matches!(state, ConnectionState::Team { .. }));
+    }
+
+    #[test]
+    fn test_player_connection() {
+        let lobby = lobby();
+        let mut team_state = ConnectionState::Unauthenticated;
+        send(register_team("team"), &lobby, &mut team_state);
+        let ConnectionState::Team { registration_token, .. } = team_state else {
+            panic!("team not registered: {:?}", team_state);
+        };
+
+        let mut state = ConnectionState::Unauthenticated;
+        let response = send(subscribe_player("player1", &registration_token), &lobby, &mut state);
+        assert_eq!(response, Some(JsonWrapper::SubscribePlayerResult(SubscribePlayerResult::Ok)));
+        assert!(matches!(state, ConnectionState::Player(ref key) if key.player == "player1"));
+
+        // Les autres joueurs ne sont pas inscrits : la partie n'a pas commencé, l'action reste sans réponse.
+        assert_eq!(send(move_to(), &lobby, &mut state), None);
+        let response = send(subscribe_player("player2", &registration_token), &lobby, &mut state);
+        assert_eq!(response, Some(JsonWrapper::SubscribePlayerResult(SubscribePlayerResult::Err(RegistrationError::AlreadyRegistered))));
+        let response = send(register_team("other"), &lobby, &mut state);
+        assert_eq!(response, Some(JsonWrapper::RegisterTeamResult(RegisterTeamResult::Err(RegistrationError::AlreadyRegistered))));
+        assert!(matches!(state, ConnectionState::Player(_)));
+    }
+}
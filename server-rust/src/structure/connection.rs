@@ -0,0 +1,47 @@
+use commun::structs::{Command, RegistrationError};
+use super::message::{register_team_result, subscribe_player_result};
+use super::team::PlayerKey;
+use tracing::warn;
+
+/// Phase du protocole pour une connexion.
+///
+/// Une connexion inscrit soit une équipe, soit un joueur ; seul un joueur inscrit peut agir.
+#[derive(Clone, Debug, Default)]
+pub enum ConnectionState {
+    /// Aucune inscription sur cette connexion.
+    #[default]
+    Unauthenticated,
+    /// La connexion a inscrit une équipe.
+    Team { game: String, registration_token: String },
+    /// La connexion est celle d'un joueur inscrit.
+    Player(PlayerKey),
+}
+
+impl ConnectionState {
+    /// Partie à laquelle la connexion est rattachée.
+    pub fn game(&self) -> Option<&str> {
+        match self {
+            ConnectionState::Unauthenticated => None,
+            ConnectionState::Team { game, .. } => Some(game),
+            ConnectionState::Player(key) => Some(&key.game),
+        }
+    }
+
+    /// Réponse à une commande non autorisée dans la phase courante de la connexion.
+    ///
+    /// Retourne `None` si la connexion doit être fermée : une action n'y est rattachée à aucun joueur inscrit,
+    /// et aucune réponse du protocole ne peut lui être adressée.
+    pub fn reject(&self, command: &Command) -> Option<String> {
+        match self {
+            ConnectionState::Team { registration_token, .. } => {
+                warn!("Command {:?} not allowed on the registration connection of team {}", command, registration_token)
+            }
+            _ => warn!("Command {:?} not allowed on {:?} connection", command, self),
+        }
+        match command {
+            Command::RegisterTeam { .. } => Some(register_team_result(Err(RegistrationError::AlreadyRegistered))),
+            Command::SubscribePlayer { .. } => Some(subscribe_player_result(Err(RegistrationError::AlreadyRegistered))),
+            Command::Action(_) => None,
+        }
+    }
+}
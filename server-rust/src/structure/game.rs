@@ -0,0 +1,267 @@
+use std::collections::HashMap;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use super::challenge::ChallengeBoard;
+use super::challenge_kind::ChallengeRegistry;
+use super::maze::{challenge_board, Maze};
+use super::player::Player;
+use super::spawn::{SpawnPolicy, Spawner};
+use commun::structs::RegistrationError;
+use super::team::{validate_team_name, GamePhase, PlayerKey, TeamManager};
+use tracing::info;
+
+/// Séparateur entre le nom de la partie et celui de l'équipe (`partie/équipe`).
+pub const GAME_SEPARATOR: char = '/';
+
+/// Réglages des parties créées par le serveur.
+#[derive(Clone, Debug)]
+pub struct GameSettings {
+    /// Graine de la première partie ; la graine de chaque nouvelle partie est incrémentée.
+    pub seed: u64,
+    /// Largeur et hauteur du labyrinthe, en cellules.
+    pub maze_size: usize,
+    /// Nombre d'équipes attendues avant de fermer les inscriptions.
+    pub teams_per_game: usize,
+    /// Choix du point de départ des joueurs.
+    pub spawn: SpawnPolicy,
+    /// Répertoire où sont écrits les résultats des parties.
+    pub results_dir: PathBuf,
+}
+
+/// Partie indépendante : son labyrinthe, ses équipes, ses défis et son déroulement.
+pub struct Game {
+    name: String,
+    pub maze: Maze,
+    pub teams: TeamManager,
+    pub challenges: ChallengeBoard,
+    spawner: Spawner,
+}
+
+impl Game {
+    pub fn new(name: &str, seed: u64, settings: &GameSettings, registry: &ChallengeRegistry) -> Self {
+        info!("Creating game '{}' with seed {}", name, seed);
+        let maze = Maze::generate(settings.maze_size, settings.maze_size, seed);
+        // Le placement des défis et les points de départ découlent tous deux de la graine de la partie ;
+        // aucun joueur ne démarre sur un défi.
+        let mut rng = StdRng::seed_from_u64(seed);
+        let challenges = challenge_board(registry, &maze, &mut rng);
+        let spawner = Spawner::new(settings.spawn, rng.gen(), challenges.cells());
+        Self {
+            name: name.to_string(),
+            maze,
+            teams: TeamManager::new(name, settings.teams_per_game, settings.results_dir.clone()),
+            challenges,
+            spawner,
+        }
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Inscrit un joueur dans l'équipe inscrite avec ce jeton. Son point de départ n'est tiré qu'une fois
+    /// l'inscription validée : une tentative refusée ne modifie pas les départs suivants.
+    pub fn register_player(&mut self, registration_token: String, name: String) -> Result<Player, RegistrationError> {
+        let Self { maze, teams, spawner, .. } = self;
+        teams.register_player(registration_token.clone(), name, || spawner.spawn(maze, &registration_token))
+    }
+
+    pub fn phase(&self) -> GamePhase {
+        self.teams.phase()
+    }
+
+    /// Enregistre la fermeture de la connexion d'un joueur, qui peut terminer la partie.
+    pub fn disconnect_player(&mut self, key: &PlayerKey) {
+        self.teams.record_disconnection(key);
+        self.update_phase();
+    }
+
+    /// Fait avancer la partie selon l'état des inscriptions et des joueurs.
+    pub fn update_phase(&mut self) {
+        self.teams.update_phase(&self.maze);
+    }
+}
+
+/// Parties hébergées par le serveur.
+///
+/// Les parties sont indexées par nom et par jeton d'inscription de leurs équipes : les retrouver ne verrouille
+/// aucune partie.
+pub struct Lobby {
+    settings: GameSettings,
+    registry: ChallengeRegistry,
+    /// Parties, dans l'ordre de leur création.
+    games: Vec<Arc<Mutex<Game>>>,
+    games_by_name: HashMap<String, Arc<Mutex<Game>>>,
+    games_by_token: HashMap<String, Arc<Mutex<Game>>>,
+}
+
+impl Lobby {
+    pub fn new(settings: GameSettings, registry: ChallengeRegistry) -> Self {
+        info!("Initializing lobby: {:?}", settings);
+        Self {
+            settings,
+            registry,
+            games: vec![],
+            games_by_name: HashMap::new(),
+            games_by_token: HashMap::new(),
+        }
+    }
+
+    /// Partie rejointe par une nouvelle équipe : celle désignée par le préfixe `partie/` du nom de l'équipe,
+    /// sinon la première partie dont les inscriptions sont ouvertes. La partie est créée au besoin ; une partie
+    /// créée sans nom reçoit le premier nom `game-<n>` libre.
+    ///
+    /// Un nom d'équipe invalide est refusé avant de créer une partie. Une partie désignée par son nom est
+    /// rejointe même si ses inscriptions sont closes : l'inscription de l'équipe y est alors refusée.
+    pub fn assign(&mut self, team_name: &str) -> Result<Arc<Mutex<Game>>, RegistrationError> {
+        validate_team_name(team_name)?;
+
+        let requested = team_name
+            .split_once(GAME_SEPARATOR)
+            .map(|(game, _)| game)
+            .filter(|game| !game.is_empty());
+
+        let existing = match requested {
+            Some(name) => self.game(name),
+            None => self
+                .games
+                .iter()
+                .find(|game| game.lock().unwrap().phase() == GamePhase::Registration)
+                .cloned(),
+        };
+        let game = existing.unwrap_or_else(|| {
+            let name = requested.map(str::to_string).unwrap_or_else(|| self.free_game_name());
+            self.create_game(&name)
+        });
+        info!("Team '{}' assigned to game '{}'", team_name, game.lock().unwrap().name());
+        Ok(game)
+    }
+
+    /// Enregistre le jeton d'inscription d'une équipe inscrite dans cette partie.
+    pub fn record_team(&mut self, registration_token: &str, game: Arc<Mutex<Game>>) {
+        self.games_by_token.insert(registration_token.to_string(), game);
+    }
+
+    pub fn game(&self, name: &str) -> Option<Arc<Mutex<Game>>> {
+        self.games_by_name.get(name).cloned()
+    }
+
+    /// Partie de l'équipe inscrite avec ce jeton.
+    pub fn game_for_token(&self, registration_token: &str) -> Option<Arc<Mutex<Game>>> {
+        self.games_by_token.get(registration_token).cloned()
+    }
+
+    /// Premier nom `game-<n>` qui n'est pas déjà celui d'une partie, nommée explicitement ou non.
+    fn free_game_name(&self) -> String {
+        (self.games.len() + 1..)
+            .map(|index| format!("game-{}", index))
+            .find(|name| !self.games_by_name.contains_key(name))
+            .unwrap_or_default()
+    }
+
+    fn create_game(&mut self, name: &str) -> Arc<Mutex<Game>> {
+        let seed = self.settings.seed.wrapping_add(self.games.len() as u64);
+        let game = Arc::new(Mutex::new(Game::new(name, seed, &self.settings, &self.registry)));
+        self.games.push(game.clone());
+        self.games_by_name.insert(name.to_string(), game.clone());
+        game
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::net::{TcpListener, TcpStream};
+    use crate::structure::connection::ConnectionState;
+    use crate::structure::team::TeamCommand;
+
+    fn lobby(teams_per_game: usize) -> Lobby {
+        let settings = GameSettings { seed: 0, maze_size: 3, teams_per_game, spawn: SpawnPolicy::Random, results_dir: env::temp_dir() };
+        Lobby::new(settings, ChallengeRegistry::builtin())
+    }
+
+    fn name(game: &Arc<Mutex<Game>>) -> String {
+        game.lock().unwrap().name().to_string()
+    }
+
+    /// Inscrit une équipe dans la partie et retourne son jeton d'inscription.
+    fn register_team(lobby: &mut Lobby, game: Arc<Mutex<Game>>, team: &str) -> String {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let _client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (server, _) = listener.accept().unwrap();
+        match TeamCommand::process(TeamCommand::Create(team.to_string()), server, game.clone()) {
+            Some(ConnectionState::Team { registration_token, .. }) => {
+                lobby.record_team(&registration_token, game);
+                registration_token
+            }
+            state => panic!("unexpected state {:?}", state),
+        }
+    }
+
+    #[test]
+    fn test_assign_fills_open_games_first() {
+        let mut lobby = lobby(2);
+        let first = lobby.assign("alpha").unwrap();
+        register_team(&mut lobby, first.clone(), "alpha");
+        assert!(Arc::ptr_eq(&lobby.assign("beta").unwrap(), &first));
+        register_team(&mut lobby, first.clone(), "beta");
+
+        // Les inscriptions de la première partie sont closes : une nouvelle partie est créée.
+        let second = lobby.assign("gamma").unwrap();
+        assert!(!Arc::ptr_eq(&second, &first));
+        assert_eq!((name(&first), name(&second)), ("game-1".to_string(), "game-2".to_string()));
+    }
+
+    #[test]
+    fn test_assign_by_game_name() {
+        let mut lobby = lobby(2);
+        let ranked = lobby.assign("ranked/alpha").unwrap();
+        assert_eq!(name(&ranked), "ranked");
+        assert!(Arc::ptr_eq(&lobby.assign("ranked/beta").unwrap(), &ranked));
+        assert_eq!(lobby.assign("bad name").err(), Some(RegistrationError::InvalidName));
+        assert_eq!(lobby.games.len(), 1);
+    }
+
+    #[test]
+    fn test_assign_skips_names_already_taken() {
+        let mut lobby = lobby(1);
+        let named = lobby.assign("game-2/alpha").unwrap();
+        register_team(&mut lobby, named.clone(), "game-2/alpha");
+
+        let automatic = lobby.assign("beta").unwrap();
+        assert_eq!(name(&automatic), "game-3");
+        assert!(Arc::ptr_eq(&lobby.game("game-2").unwrap(), &named));
+    }
+
+    #[test]
+    fn test_game_for_token() {
+        let mut lobby = lobby(1);
+        let first = lobby.assign("alpha").unwrap();
+        let alpha = register_team(&mut lobby, first.clone(), "alpha");
+        let second = lobby.assign("beta").unwrap();
+        let beta = register_team(&mut lobby, second.clone(), "beta");
+
+        assert!(Arc::ptr_eq(&lobby.game_for_token(&alpha).unwrap(), &first));
+        assert!(Arc::ptr_eq(&lobby.game_for_token(&beta).unwrap(), &second));
+        assert!(lobby.game_for_token("unknown").is_none());
+    }
+
+    #[test]
+    fn test_refused_registration_does_not_draw_a_spawn() {
+        let spawn_of_first_player = |failed_attempts: &[&str]| {
+            let mut lobby = lobby(1);
+            let game = lobby.assign("alpha").unwrap();
+            let token = register_team(&mut lobby, game.clone(), "alpha");
+            let mut game = game.lock().unwrap();
+            for name in failed_attempts {
+                assert!(game.register_player(token.clone(), name.to_string()).is_err());
+            }
+            let position = game.register_player(token, "player1".to_string()).unwrap().get_position();
+            (position.x, position.y)
+        };
+        assert_eq!(spawn_of_first_player(&["bad name", "", "bad/name"]), spawn_of_first_player(&[]));
+    }
+}
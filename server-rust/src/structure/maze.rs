@@ -1,19 +1,18 @@
+use std::collections::{HashMap, VecDeque};
 use commun::structs::{ActionError, RelativeDirection};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use super::challenge::{ChallengeBoard, ChallengePosition};
+use super::challenge_kind::ChallengeRegistry;
 use super::player::Player;
 use tracing::{info, warn};
 
-pub const WIDTH: usize = 7;
-pub const HEIGHT: usize = 7;
+/// Taille minimale (en cellules) d'un labyrinthe généré : le point de départ et les défis doivent y tenir.
+pub const MIN_MAZE_SIZE: usize = 3;
 
-pub const MAZE: [[char; WIDTH]; HEIGHT] = [
-    ['•','-','•','-','•','-','•'],
-    ['|',' ',' ',' ',' ',' ','|'],
-    ['•','-','•',' ','•',' ','•'],
-    ['|',' ','|',' ','|',' ','|'],
-    ['•',' ','•',' ','•','-','•'],
-    ['|',' ',' ',' ',' ','*','|'],
-    ['•','-','•','-','•','-','•']
-];
+/// Défis placés dans chaque labyrinthe, par nom du type de défi dans le registre.
+pub const CHALLENGES: [&str; 2] = ["secret_sum_modulo_10", "secret_sum_modulo_7"];
 
 const WALLS: [char; 3] = ['-','|','•'];
 
@@ -23,43 +22,197 @@ pub struct Point {
     pub y: i32
 }
 
-pub fn check_movement_possible(direction: RelativeDirection, player: &Player) -> Result<Point, ActionError> {
-    let position = player.get_position();
-    info!("Checking movement: {:?} from position: x={}, y={}", direction, position.x, position.y);
+/// Labyrinthe d'une partie : grille de coins (`•`), murs (`-`, `|`), passages (` `) et sortie (`*`).
+///
+/// La cellule `(x, y)` du labyrinthe occupe la case `(2x + 1, 2y + 1)` de la grille.
+#[derive(Clone, Debug)]
+pub struct Maze {
+    grid: Vec<Vec<char>>,
+}
 
-    let mut new_position = position.clone();
+impl Maze {
+    /// Génère un labyrinthe parfait de `width` x `height` cellules par parcours en profondeur aléatoire,
+    /// reproductible à partir de `seed`. La sortie est dans la cellule opposée au coin supérieur gauche.
+    pub fn generate(width: usize, height: usize, seed: u64) -> Self {
+        let (width, height) = (width.max(MIN_MAZE_SIZE), height.max(MIN_MAZE_SIZE));
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut grid: Vec<Vec<char>> = (0..2 * height + 1)
+            .map(|y| {
+                (0..2 * width + 1)
+                    .map(|x| match (x % 2, y % 2) {
+                        (0, 0) => '•',
+                        (1, 0) => '-',
+                        (0, 1) => '|',
+                        _ => ' ',
+                    })
+                    .collect()
+            })
+            .collect();
 
-    match direction {
-        RelativeDirection::Front => {
-            if WALLS.contains(&MAZE[position.y as usize - 1][position.x as usize]) {
-                warn!("Wall detected in Front!");
-                return Err(ActionError::CannotPassThroughWall);
+        let mut visited = vec![vec![false; width]; height];
+        visited[0][0] = true;
+        let mut stack = vec![(0_usize, 0_usize)];
+        while let Some(&(x, y)) = stack.last() {
+            let neighbours: Vec<(usize, usize)> = [(0, -1), (1, 0), (0, 1), (-1, 0)]
+                .iter()
+                .map(|(dx, dy)| (x as i32 + dx, y as i32 + dy))
+                .filter(|&(nx, ny)| nx >= 0 && ny >= 0 && (nx as usize) < width && (ny as usize) < height)
+                .map(|(nx, ny)| (nx as usize, ny as usize))
+                .filter(|&(nx, ny)| !visited[ny][nx])
+                .collect();
+            match neighbours.choose(&mut rng) {
+                Some(&(nx, ny)) => {
+                    // Le mur entre deux cellules voisines est au milieu de leurs cases.
+                    grid[y + ny + 1][x + nx + 1] = ' ';
+                    visited[ny][nx] = true;
+                    stack.push((nx, ny));
+                }
+                None => {
+                    stack.pop();
+                }
             }
-            new_position.y -= 2;
-        },
-        RelativeDirection::Right => {
-            if WALLS.contains(&MAZE[position.y as usize][position.x as usize + 1]) {
-                warn!("Wall detected on the Right!");
-                return Err(ActionError::CannotPassThroughWall);
+        }
+        grid[2 * height - 1][2 * width - 1] = '*';
+
+        info!("Generated a {}x{} maze with seed {}", width, height, seed);
+        Self { grid }
+    }
+
+    /// Case de la grille, ou `None` hors du labyrinthe.
+    pub fn at(&self, x: i32, y: i32) -> Option<char> {
+        if x < 0 || y < 0 {
+            return None;
+        }
+        self.grid.get(y as usize)?.get(x as usize).copied()
+    }
+
+    /// Cellules du labyrinthe, hors sortie.
+    pub fn open_cells(&self) -> Vec<Point> {
+        (1..self.grid.len())
+            .step_by(2)
+            .flat_map(|y| (1..self.grid[y].len()).step_by(2).map(move |x| Point { x: x as i32, y: y as i32 }))
+            .filter(|position| !self.is_exit(position))
+            .collect()
+    }
+
+    /// Nombre de déplacements entre chaque cellule accessible et la sortie (parcours en largeur depuis la sortie).
+    pub fn distances_from_exit(&self) -> HashMap<(i32, i32), u32> {
+        let mut distances = HashMap::new();
+        let mut queue = VecDeque::new();
+        for (y, row) in self.grid.iter().enumerate() {
+            for (x, case) in row.iter().enumerate() {
+                if *case == '*' {
+                    distances.insert((x as i32, y as i32), 0);
+                    queue.push_back((x as i32, y as i32));
+                }
             }
-            new_position.x += 2;
-        },
-        RelativeDirection::Back => {
-            if WALLS.contains(&MAZE[position.y as usize + 1][position.x as usize]) {
-                warn!("Wall detected in Back!");
-                return Err(ActionError::CannotPassThroughWall);
+        }
+        while let Some((x, y)) = queue.pop_front() {
+            let distance = distances[&(x, y)];
+            for (dx, dy) in [(0, -1), (1, 0), (0, 1), (-1, 0)] {
+                let next = (x + 2 * dx, y + 2 * dy);
+                if !self.is_wall(x + dx, y + dy) && !distances.contains_key(&next) {
+                    distances.insert(next, distance + 1);
+                    queue.push_back(next);
+                }
             }
-            new_position.y += 2;
-        },
-        RelativeDirection::Left => {
-            if WALLS.contains(&MAZE[position.y as usize][position.x as usize - 1]) {
-                warn!("Wall detected on the Left!");
-                return Err(ActionError::CannotPassThroughWall);
+        }
+        distances
+    }
+
+    /// Indique si la cellule est la sortie du labyrinthe (`*`).
+    pub fn is_exit(&self, position: &Point) -> bool {
+        self.at(position.x, position.y) == Some('*')
+    }
+
+    fn is_wall(&self, x: i32, y: i32) -> bool {
+        self.at(x, y).is_none_or(|case| WALLS.contains(&case))
+    }
+
+    pub fn check_movement_possible(&self, direction: RelativeDirection, player: &Player) -> Result<Point, ActionError> {
+        let position = player.get_position();
+        info!("Checking movement: {:?} from position: x={}, y={}", direction, position.x, position.y);
+
+        let mut new_position = position.clone();
+
+        match direction {
+            RelativeDirection::Front => {
+                if self.is_wall(position.x, position.y - 1) {
+                    warn!("Wall detected in Front!");
+                    return Err(ActionError::CannotPassThroughWall);
+                }
+                new_position.y -= 2;
+            },
+            RelativeDirection::Right => {
+                if self.is_wall(position.x + 1, position.y) {
+                    warn!("Wall detected on the Right!");
+                    return Err(ActionError::CannotPassThroughWall);
+                }
+                new_position.x += 2;
+            },
+            RelativeDirection::Back => {
+                if self.is_wall(position.x, position.y + 1) {
+                    warn!("Wall detected in Back!");
+                    return Err(ActionError::CannotPassThroughWall);
+                }
+                new_position.y += 2;
+            },
+            RelativeDirection::Left => {
+                if self.is_wall(position.x - 1, position.y) {
+                    warn!("Wall detected on the Left!");
+                    return Err(ActionError::CannotPassThroughWall);
+                }
+                new_position.x -= 2;
             }
-            new_position.x -= 2;
         }
+
+        info!("Movement possible to: x={}, y={}", new_position.x, new_position.y);
+        Ok(new_position)
     }
+}
 
-    info!("Movement possible to: x={}, y={}", new_position.x, new_position.y);
-    Ok(new_position)
-}
\ No newline at end of file
+/// Crée l'ensemble des défis du labyrinthe, aucun n'étant encore résolu.
+///
+/// Chaque défi occupe une cellule distincte, tirée parmi les cellules du labyrinthe avec le générateur de la partie.
+/// Les défis dont le type est absent du registre sont ignorés.
+pub fn challenge_board(registry: &ChallengeRegistry, maze: &Maze, rng: &mut StdRng) -> ChallengeBoard {
+    let cells = maze.open_cells();
+    let positions: Vec<Point> = cells.choose_multiple(rng, CHALLENGES.len()).cloned().collect();
+    ChallengeBoard::new(
+        CHALLENGES
+            .iter()
+            .zip(positions)
+            .filter_map(|(name, position)| match registry.get(name) {
+                Some(kind) => Some(ChallengePosition::new(position, name, kind)),
+                None => {
+                    warn!("Unknown challenge kind '{}' at x={}, y={}, skipping", name, position.x, position.y);
+                    None
+                }
+            })
+            .collect(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Cellules des défis placés dans un labyrinthe généré à partir de `seed`.
+    fn challenge_cells(maze: &Maze, seed: u64) -> Vec<(i32, i32)> {
+        let board = challenge_board(&ChallengeRegistry::builtin(), maze, &mut StdRng::seed_from_u64(seed));
+        maze.open_cells()
+            .into_iter()
+            .filter(|cell| board.at(cell).is_some())
+            .map(|cell| (cell.x, cell.y))
+            .collect()
+    }
+
+    #[test]
+    fn test_challenges_are_placed_from_the_seed() {
+        let maze = Maze::generate(5, 5, 7);
+        let cells = challenge_cells(&maze, 7);
+        assert_eq!(cells.len(), CHALLENGES.len());
+        assert_eq!(challenge_cells(&maze, 7), cells);
+        assert!((0..20).any(|seed| challenge_cells(&maze, seed) != cells));
+    }
+}
@@ -1,4 +1,5 @@
 use commun::{serde_json, structs::{ActionError, Challenge, JsonWrapper, RegisterTeamResult, RegistrationError, SubscribePlayerResult}};
+use super::maze::Maze;
 use super::player::Player;
 use tracing::{info, warn};
 
@@ -19,9 +20,8 @@ pub fn register_team_result(result: Result<(u8, String), RegistrationError>) ->
 
 pub fn subscribe_player_result(result: Result<Player, RegistrationError>) -> String {
     let message_wrapped = match result {
-        Ok(player) => {
+        Ok(_) => {
             info!("Player subscribed successfully");
-            player.get_radar_view();
             JsonWrapper::SubscribePlayerResult(SubscribePlayerResult::Ok)
         },
         Err(error) => {
@@ -33,15 +33,15 @@ pub fn subscribe_player_result(result: Result<Player, RegistrationError>) -> Str
     serde_json::to_string(&message_wrapped).unwrap()
 }
 
-pub fn action_result(result: Result<(Player, bool), ActionError>) -> String {
+pub fn action_result(result: Result<(Player, Option<Challenge>), ActionError>, maze: &Maze) -> String {
     let message_wrapped = match result {
-        Ok((player, is_challenge_position)) => {
-            if is_challenge_position {
+        Ok((player, challenge)) => {
+            if let Some(challenge) = challenge {
                 info!("Player reached a challenge position");
-                JsonWrapper::Challenge(Challenge::SecretSumModulo(10))
+                JsonWrapper::Challenge(challenge)
             } else {
                 info!("Sending updated radar view");
-                JsonWrapper::RadarView(player.get_radar_view())
+                JsonWrapper::RadarView(player.get_radar_view(maze))
             }
         },
         Err(action_err) => {
@@ -53,8 +53,8 @@ pub fn action_result(result: Result<(Player, bool), ActionError>) -> String {
     serde_json::to_string(&message_wrapped).unwrap()
 }
 
-pub fn radar_view(player: Player) -> String {
-    let message_wrapped = JsonWrapper::RadarView(player.get_radar_view());
+pub fn radar_view(player: Player, maze: &Maze) -> String {
+    let message_wrapped = JsonWrapper::RadarView(player.get_radar_view(maze));
 
     serde_json::to_string(&message_wrapped).unwrap()
 }
\ No newline at end of file
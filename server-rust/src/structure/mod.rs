@@ -4,4 +4,8 @@ pub mod team;
 pub mod message;
 pub mod maze;
 pub mod action;
-pub mod challenge;
\ No newline at end of file
+pub mod challenge;
+pub mod challenge_kind;
+pub mod connection;
+pub mod game;
+pub mod spawn;
\ No newline at end of file
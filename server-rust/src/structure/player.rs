@@ -1,6 +1,7 @@
 use commun::encodage::{encode_b64, encode_radar_view_binary};
 
-use super::maze::*;
+use std::time::{Duration, Instant};
+use super::maze::{Maze, Point};
 use tracing::{info};
 
 pub const DISTANCE: i32 = 3;
@@ -10,22 +11,26 @@ pub const SIZE_VIEW: usize = 7;
 pub struct Player {
     name: String,
     position: Point,
-    challenge_actif: bool
+    challenge_actif: bool,
+    /// Début du défi en cours, s'il y en a un.
+    challenge_since: Option<Instant>,
 }
 
 impl Player {
-    pub fn new(name: String) -> Self {
-        info!("Creating new player: {}", name);
+    pub fn new(name: String, position: Point) -> Self {
+        info!("Creating new player: {} at x={}, y={}", name, position.x, position.y);
         Self {
-            name: name,
-            position: Point {
-                x: 3,
-                y: 3
-            },
-            challenge_actif: false
+            name,
+            position,
+            challenge_actif: false,
+            challenge_since: None,
         }
     }
 
+    pub fn get_name(&self) -> &str {
+        &self.name
+    }
+
     pub fn get_position(&self) -> Point {
         self.position.clone()
     }
@@ -48,9 +53,15 @@ impl Player {
             is_challenge_actif, self.name
         );
         self.challenge_actif = is_challenge_actif;
+        self.challenge_since = is_challenge_actif.then(Instant::now);
+    }
+
+    /// Temps écoulé depuis le début du défi en cours (nul sans défi en cours).
+    pub fn get_challenge_elapsed(&self) -> Duration {
+        self.challenge_since.map(|since| since.elapsed()).unwrap_or_default()
     }
 
-    pub fn get_radar_view(self) -> String {
+    pub fn get_radar_view(self, maze: &Maze) -> String {
         info!(
             "Generating radar view for player at position x={}, y={}",
             self.position.x, self.position.y
@@ -60,28 +71,15 @@ impl Player {
         for (i, row) in radar_view.iter_mut().enumerate() {
             let maze_y: i32 = (self.position.y - DISTANCE) + (i as i32);
 
-            if maze_y < 0 || maze_y >= HEIGHT as i32 {
-                continue;
+            for (j, case) in row.iter_mut().enumerate() {
+                let maze_x = (self.position.x - DISTANCE) + (j as i32);
+                *case = maze.at(maze_x, maze_y).unwrap_or('#');
             }
-
-            let mut row_to_add: Vec<char> = vec![];
-            for i in 0..SIZE_VIEW { 
-                let maze_x = (self.position.x - DISTANCE) + (i as i32);
-                if maze_x < 0 || maze_x >= WIDTH as i32 {
-                    row_to_add.push('#');
-                    continue;
-                } else  {
-                    row_to_add.push(MAZE[maze_y as usize][maze_x as usize]);
-                }
-            }
-            row.copy_from_slice(&row_to_add);
         }
 
         let binary_radar_view = encode_radar_view_binary(radar_view);
 
-        let encode_radar_view = encode_b64(&binary_radar_view);
-
-        encode_radar_view
+        encode_b64(&binary_radar_view)
 
     }
 }
@@ -0,0 +1,161 @@
+use std::collections::{HashMap, HashSet};
+use std::str::FromStr;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use super::maze::{Maze, Point};
+use tracing::info;
+
+/// Choix du point de départ des joueurs.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SpawnPolicy {
+    /// Une cellule aléatoire pour chaque joueur.
+    Random,
+    /// Une cellule aléatoire par équipe, partagée par tous ses joueurs.
+    PerTeam,
+    /// La cellule la plus éloignée de la sortie, en nombre de déplacements.
+    FarthestFromExit,
+}
+
+impl FromStr for SpawnPolicy {
+    type Err = String;
+
+    fn from_str(name: &str) -> Result<Self, Self::Err> {
+        match name {
+            "random" => Ok(SpawnPolicy::Random),
+            "team" => Ok(SpawnPolicy::PerTeam),
+            "farthest" => Ok(SpawnPolicy::FarthestFromExit),
+            _ => Err(format!("Politique de départ inconnue : {} (random, team ou farthest)", name)),
+        }
+    }
+}
+
+/// Attribue les points de départ d'une partie, de façon reproductible à partir de sa graine.
+pub struct Spawner {
+    policy: SpawnPolicy,
+    rng: StdRng,
+    /// Point de départ de chaque équipe (par jeton d'inscription), pour `SpawnPolicy::PerTeam`.
+    team_spawns: HashMap<String, Point>,
+    /// Cellules où aucun joueur ne démarre (celles des défis).
+    reserved: HashSet<(i32, i32)>,
+}
+
+impl Spawner {
+    pub fn new(policy: SpawnPolicy, seed: u64, reserved: HashSet<(i32, i32)>) -> Self {
+        Self {
+            policy,
+            rng: StdRng::seed_from_u64(seed),
+            team_spawns: HashMap::new(),
+            reserved,
+        }
+    }
+
+    /// Point de départ d'un nouveau joueur de l'équipe `team`.
+    pub fn spawn(&mut self, maze: &Maze, team: &str) -> Point {
+        let spawn = match self.policy {
+            SpawnPolicy::Random => self.random_cell(maze),
+            SpawnPolicy::PerTeam => match self.team_spawns.get(team) {
+                Some(spawn) => spawn.clone(),
+                None => {
+                    let spawn = self.random_cell(maze);
+                    self.team_spawns.insert(team.to_string(), spawn.clone());
+                    spawn
+                }
+            },
+            SpawnPolicy::FarthestFromExit => self.farthest_cell(maze),
+        };
+        info!("Spawning player of team {} at x={}, y={} ({:?})", team, spawn.x, spawn.y, self.policy);
+        spawn
+    }
+
+    fn random_cell(&mut self, maze: &Maze) -> Point {
+        let cells: Vec<Point> = maze
+            .open_cells()
+            .into_iter()
+            .filter(|cell| !self.reserved.contains(&(cell.x, cell.y)))
+            .collect();
+        cells
+            .choose(&mut self.rng)
+            .cloned()
+            .unwrap_or(Point { x: 1, y: 1 })
+    }
+
+    /// Cellule non réservée la plus éloignée de la sortie ; en cas d'égalité, l'une d'elles au hasard.
+    fn farthest_cell(&mut self, maze: &Maze) -> Point {
+        let mut distances = maze.distances_from_exit();
+        distances.retain(|cell, _| !self.reserved.contains(cell));
+        let farthest = distances.values().copied().max().unwrap_or_default();
+        let mut candidates: Vec<(i32, i32)> = distances
+            .into_iter()
+            .filter(|(_, distance)| *distance == farthest)
+            .map(|(position, _)| position)
+            .collect();
+        // Les distances sont dans une table de hachage : on trie pour rester reproductible.
+        candidates.sort();
+        candidates
+            .choose(&mut self.rng)
+            .map(|&(x, y)| Point { x, y })
+            .unwrap_or(Point { x: 1, y: 1 })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Points de départ successifs de joueurs des équipes `teams`, pour un labyrinthe et une graine donnés.
+    fn spawns(policy: SpawnPolicy, seed: u64, teams: &[&str]) -> Vec<(i32, i32)> {
+        let maze = Maze::generate(6, 6, 3);
+        let mut spawner = Spawner::new(policy, seed, HashSet::new());
+        teams.iter().map(|team| spawner.spawn(&maze, team)).map(|spawn| (spawn.x, spawn.y)).collect()
+    }
+
+    #[test]
+    fn test_spawns_are_reproducible_per_policy() {
+        let teams = ["alpha", "beta", "alpha", "gamma", "beta"];
+        for policy in [SpawnPolicy::Random, SpawnPolicy::PerTeam, SpawnPolicy::FarthestFromExit] {
+            assert_eq!(spawns(policy, 42, &teams), spawns(policy, 42, &teams), "{:?}", policy);
+        }
+        assert!((0..20).any(|seed| spawns(SpawnPolicy::Random, seed, &teams) != spawns(SpawnPolicy::Random, 42, &teams)));
+    }
+
+    #[test]
+    fn test_per_team_spawn_is_shared() {
+        let spawns = spawns(SpawnPolicy::PerTeam, 42, &["alpha", "beta", "alpha", "beta"]);
+        assert_eq!(spawns[0], spawns[2]);
+        assert_eq!(spawns[1], spawns[3]);
+    }
+
+    #[test]
+    fn test_farthest_spawn_maximizes_distance_to_exit() {
+        let maze = Maze::generate(6, 6, 3);
+        let distances = maze.distances_from_exit();
+        let farthest = distances.values().max().copied();
+        for spawn in spawns(SpawnPolicy::FarthestFromExit, 42, &["alpha", "beta"]) {
+            assert_eq!(distances.get(&spawn).copied(), farthest);
+        }
+    }
+
+    #[test]
+    fn test_spawn_avoids_reserved_cells() {
+        let maze = Maze::generate(6, 6, 3);
+        let free = maze.open_cells()[5].clone();
+        let reserved: HashSet<(i32, i32)> =
+            maze.open_cells().into_iter().map(|cell| (cell.x, cell.y)).filter(|&cell| cell != (free.x, free.y)).collect();
+        for policy in [SpawnPolicy::Random, SpawnPolicy::PerTeam, SpawnPolicy::FarthestFromExit] {
+            let mut spawner = Spawner::new(policy, 42, reserved.clone());
+            for team in ["alpha", "beta", "alpha"] {
+                let spawn = spawner.spawn(&maze, team);
+                assert_eq!((spawn.x, spawn.y), (free.x, free.y), "{:?}", policy);
+            }
+        }
+    }
+
+    #[test]
+    fn test_parse_spawn_policy() {
+        assert_eq!("random".parse(), Ok(SpawnPolicy::Random));
+        assert_eq!("team".parse(), Ok(SpawnPolicy::PerTeam));
+        assert_eq!("farthest".parse(), Ok(SpawnPolicy::FarthestFromExit));
+        assert!("nearest".parse::<SpawnPolicy>().is_err());
+    }
+}
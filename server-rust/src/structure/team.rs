@@ -1,39 +1,174 @@
 use std::collections::HashMap;
+use std::fs;
+use std::path::PathBuf;
 use std::sync::{Arc, Mutex};
 use std::net::TcpStream;
-use commun::{utils::generate_acess_key, structs::RegistrationError};
+use std::time::{Duration, Instant};
+use commun::{serde_json::{self, json, Value}, utils::generate_acess_key, structs::RegistrationError};
 use crate::send_to_client;
 use crate::structure::message::radar_view;
+use super::connection::ConnectionState;
+use super::game::{Game, GAME_SEPARATOR};
+use super::maze::{Maze, Point};
 use super::message::{register_team_result, subscribe_player_result };
 use super::player::Player;
 use tracing::{info, warn, error};
 
 const MAX_PLAYER: u8 = 3;
+/// Longueur maximale, en caractères, d'un nom d'équipe ou de joueur.
+pub const MAX_NAME_LENGTH: usize = 32;
+/// Préfixe des fichiers où sont écrits les résultats de chaque partie (`results-<partie>.json`).
+pub const RESULTS_FILE_PREFIX: &str = "results";
 
 pub enum TeamCommand {
     Create(String),
     SubscribePlayer { name: String, registration_token: String },
 }
 
+/// Identité du joueur associé à une connexion, connue après son inscription.
+#[derive(Clone, Debug)]
+pub struct PlayerKey {
+    pub game: String,
+    pub registration_token: String,
+    pub player: String,
+}
+
+/// Phase de la partie.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum GamePhase {
+    /// Les équipes s'inscrivent.
+    Registration,
+    /// Les inscriptions d'équipes sont closes : la partie attend que chaque équipe ait ses `MAX_PLAYER` joueurs.
+    WaitingForPlayers,
+    /// La partie est en cours : les joueurs peuvent agir.
+    Running,
+    /// Tous les joueurs sont sortis du labyrinthe ou ont quitté la partie.
+    Finished,
+}
+
 pub struct TeamManager {
+    /// Nom de la partie.
+    game: String,
+    /// Nombre d'équipes attendues avant de fermer les inscriptions.
+    expected_teams: usize,
     teams: HashMap<String, Team>,
+    phase: GamePhase,
+    /// Connexions des joueurs inscrits, en attente du début de la partie pour recevoir leur première vue radar.
+    waiting_room: Vec<(PlayerKey, TcpStream)>,
+    /// Répertoire où sont écrits les résultats de la partie.
+    results_dir: PathBuf,
 }
 
 #[derive(Clone)]
 struct Team {
     name: String,
     players: Vec<Player>,
+    /// Début de la partie, une fois tous les joueurs inscrits.
+    started_at: Instant,
+    /// Statistiques de chaque joueur, par nom.
+    stats: HashMap<String, PlayerStats>,
+}
+
+/// Statistiques d'un joueur, tenues par le serveur.
+#[derive(Clone, Debug, Default)]
+struct PlayerStats {
+    /// Déplacements demandés, y compris ceux refusés, et pénalités.
+    moves: u32,
+    challenges_solved: u32,
+    /// Temps mis pour sortir du labyrinthe, depuis le début de la partie.
+    exit_time: Option<Duration>,
+    /// Le joueur a fermé sa connexion sans être sorti du labyrinthe.
+    disconnected: bool,
+}
+
+/// Résultat d'une équipe en fin de partie.
+#[derive(Clone, Debug)]
+pub struct TeamResult {
+    pub team: String,
+    pub moves: u32,
+    pub challenges_solved: u32,
+    pub players_exited: usize,
+    pub players_disconnected: usize,
+    pub players: usize,
+    /// Temps mis par le dernier joueur de l'équipe pour sortir, si tous sont sortis.
+    pub exit_time: Option<Duration>,
+    player_results: Vec<(String, PlayerStats)>,
+}
+
+impl TeamResult {
+    fn to_json(&self, rank: usize) -> Value {
+        let players: Vec<Value> = self
+            .player_results
+            .iter()
+            .map(|(name, stats)| {
+                json!({
+                    "name": name,
+                    "moves": stats.moves,
+                    "challenges_solved": stats.challenges_solved,
+                    "exit_time_secs": stats.exit_time.map(|time| time.as_secs_f64()),
+                    "disconnected": stats.disconnected,
+                })
+            })
+            .collect();
+        json!({
+            "rank": rank,
+            "team": self.team,
+            "moves": self.moves,
+            "challenges_solved": self.challenges_solved,
+            "players_exited": self.players_exited,
+            "players_disconnected": self.players_disconnected,
+            "players": players,
+            "exit_time_secs": self.exit_time.map(|time| time.as_secs_f64()),
+        })
+    }
+}
+
+/// Vérifie un nom : non vide, au plus `MAX_NAME_LENGTH` caractères, composé de lettres et chiffres ASCII,
+/// de `_`, de `-` et des caractères de `extra`.
+fn validate_name(name: &str, extra: &[char]) -> Result<(), RegistrationError> {
+    let valid = !name.is_empty()
+        && name.chars().count() <= MAX_NAME_LENGTH
+        && name
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '-' || extra.contains(&c));
+    if !valid {
+        warn!("Invalid name: {:?}", name);
+        return Err(RegistrationError::InvalidName);
+    }
+    Ok(())
+}
+
+/// Vérifie un nom d'équipe, qui peut désigner sa partie (`partie/équipe`).
+pub fn validate_team_name(name: &str) -> Result<(), RegistrationError> {
+    validate_name(name, &[GAME_SEPARATOR])
+}
+
+fn validate_player_name(name: &str) -> Result<(), RegistrationError> {
+    validate_name(name, &[])
 }
 
 impl TeamManager {
-    pub fn new() -> Self {
-        info!("Initializing new TeamManager");
+    pub fn new(game: &str, expected_teams: usize, results_dir: PathBuf) -> Self {
+        info!("Initializing new TeamManager for game '{}'", game);
         Self {
+            game: game.to_string(),
+            expected_teams,
             teams: HashMap::new(),
+            phase: GamePhase::Registration,
+            waiting_room: vec![],
+            results_dir,
         }
     }
 
     fn create_team(&mut self, name: &String) -> Result<String, RegistrationError> {
+        validate_team_name(name)?;
+
+        // Le protocole n'a pas d'erreur dédiée : les équipes de la partie sont déjà toutes inscrites.
+        if self.phase != GamePhase::Registration {
+            warn!("Team registration is closed, game is {:?}", self.phase);
+            return Err(RegistrationError::AlreadyRegistered);
+        }
+
         if self.teams.values().any(|team| team.name == *name) {
             warn!("Team '{}' already exists", name);
             return Err(RegistrationError::AlreadyRegistered);
@@ -42,6 +177,8 @@ impl TeamManager {
         let team = Team {
             name: name.to_string(),
             players: vec![],
+            started_at: Instant::now(),
+            stats: HashMap::new(),
         };
 
         let access_key = generate_acess_key();
@@ -51,16 +188,30 @@ impl TeamManager {
         Ok(access_key)
     }
 
-    fn register_player(&mut self, access_key: String, name: String) -> Result<Player, RegistrationError> {
+    /// Inscrit un joueur dans l'équipe ; son point de départ n'est choisi (`spawn`) qu'une fois l'inscription validée.
+    pub fn register_player(&mut self, access_key: String, name: String, spawn: impl FnOnce() -> Point) -> Result<Player, RegistrationError> {
         match self.teams.get_mut(&access_key) {
             Some(team) => {
+                if matches!(self.phase, GamePhase::Running | GamePhase::Finished) {
+                    warn!("Game is {:?}, player '{}' cannot join team '{}'", self.phase, name, team.name);
+                    return Err(RegistrationError::TooManyPlayers);
+                }
+
+                validate_player_name(&name)?;
+
+                if team.players.iter().any(|player| player.get_name() == name) {
+                    warn!("Player '{}' is already registered in team '{}'", name, team.name);
+                    return Err(RegistrationError::AlreadyRegistered);
+                }
+
                 if team.players.len() >= MAX_PLAYER.into() {
                     warn!("Team '{}' already has the maximum number of players", team.name);
                     return Err(RegistrationError::TooManyPlayers);
                 }
 
-                let player = Player::new(name.clone());
+                let player = Player::new(name.clone(), spawn());
                 team.players.push(player.clone());
+                team.stats.insert(name.clone(), PlayerStats::default());
 
                 info!("Player '{}' successfully registered in team '{}'", name, team.name);
                 Ok(player)
@@ -73,13 +224,226 @@ impl TeamManager {
     }
 }
 
+impl TeamManager {
+    pub fn phase(&self) -> GamePhase {
+        self.phase
+    }
+
+    /// Joueur inscrit associé à une connexion.
+    pub fn player_mut(&mut self, key: &PlayerKey) -> Option<&mut Player> {
+        self.teams
+            .get_mut(&key.registration_token)?
+            .players
+            .iter_mut()
+            .find(|player| player.get_name() == key.player)
+    }
+
+    /// Fait avancer la partie : fermeture des inscriptions, démarrage quand tous les joueurs sont inscrits,
+    /// fin quand tous sont sortis ou ont quitté la partie.
+    pub fn update_phase(&mut self, maze: &Maze) {
+        if self.phase == GamePhase::Registration && self.teams.len() >= self.expected_teams {
+            self.set_phase(GamePhase::WaitingForPlayers);
+        }
+        if self.phase == GamePhase::WaitingForPlayers && self.all_players_subscribed() {
+            self.set_phase(GamePhase::Running);
+            self.start_game(maze);
+        }
+        if self.phase == GamePhase::Running && self.is_game_over() {
+            self.set_phase(GamePhase::Finished);
+            self.publish_results();
+        }
+    }
+
+    fn set_phase(&mut self, phase: GamePhase) {
+        info!("Game '{}' phase: {:?} -> {:?}", self.game, self.phase, phase);
+        self.phase = phase;
+    }
+
+    fn all_players_subscribed(&self) -> bool {
+        self.teams.values().all(|team| team.players.len() >= MAX_PLAYER.into())
+    }
+
+    /// Place un joueur inscrit en salle d'attente jusqu'au début de la partie.
+    fn join_waiting_room(&mut self, key: PlayerKey, stream: TcpStream) {
+        self.waiting_room.push((key, stream));
+        info!(
+            "{} players waiting for game '{}' to start ({} expected)",
+            self.waiting_room.len(),
+            self.game,
+            self.expected_teams * MAX_PLAYER as usize
+        );
+    }
+
+    /// Démarre la partie : tous les joueurs en attente reçoivent leur première vue radar en même temps.
+    fn start_game(&mut self, maze: &Maze) {
+        info!("All players subscribed, starting game '{}'", self.game);
+        let started_at = Instant::now();
+        for team in self.teams.values_mut() {
+            team.started_at = started_at;
+        }
+
+        for (key, stream) in std::mem::take(&mut self.waiting_room) {
+            let Some(player) = self.player_mut(&key) else {
+                continue;
+            };
+            if let Err(e) = send_to_client(&stream, radar_view(player.clone(), maze)) {
+                error!("Failed to send initial radar view to '{}': {}", key.player, e);
+            }
+        }
+    }
+
+    fn player_stats(&mut self, key: &PlayerKey) -> Option<(&mut PlayerStats, Instant)> {
+        let team = self.teams.get_mut(&key.registration_token)?;
+        let started_at = team.started_at;
+        team.stats.get_mut(&key.player).map(|stats| (stats, started_at))
+    }
+
+    /// Indique si le joueur est déjà sorti du labyrinthe.
+    pub fn has_exited(&self, key: &PlayerKey) -> bool {
+        self.teams
+            .get(&key.registration_token)
+            .and_then(|team| team.stats.get(&key.player))
+            .is_some_and(|stats| stats.exit_time.is_some())
+    }
+
+    /// Enregistre un déplacement demandé par un joueur, et sa sortie s'il a atteint la sortie.
+    ///
+    /// Les déplacements d'un joueur déjà sorti ne sont plus comptés.
+    pub fn record_move(&mut self, key: &PlayerKey, exited: bool) {
+        let Some((stats, started_at)) = self.player_stats(key) else {
+            return;
+        };
+        if stats.exit_time.is_some() {
+            warn!("Player '{}' already exited the maze, move not counted", key.player);
+            return;
+        }
+        stats.moves += 1;
+        if exited && stats.exit_time.is_none() {
+            let exit_time = started_at.elapsed();
+            stats.exit_time = Some(exit_time);
+            info!("Player '{}' exited the maze after {:.1}s and {} moves", key.player, exit_time.as_secs_f64(), stats.moves);
+        }
+    }
+
+    /// Ajoute des déplacements de pénalité au score d'un joueur.
+    pub fn record_penalty(&mut self, key: &PlayerKey, moves: u32) {
+        if let Some((stats, _)) = self.player_stats(key) {
+            stats.moves += moves;
+            info!("Player '{}' receives a penalty of {} moves", key.player, moves);
+        }
+    }
+
+    /// Enregistre un défi résolu par un joueur.
+    pub fn record_challenge_solved(&mut self, key: &PlayerKey) {
+        if let Some((stats, _)) = self.player_stats(key) {
+            stats.challenges_solved += 1;
+        }
+    }
+
+    /// Enregistre la fermeture de la connexion d'un joueur : s'il n'est pas sorti du labyrinthe, il quitte la partie.
+    pub fn record_disconnection(&mut self, key: &PlayerKey) {
+        self.waiting_room
+            .retain(|(waiting, _)| waiting.registration_token != key.registration_token || waiting.player != key.player);
+        if self.phase == GamePhase::Finished {
+            return;
+        }
+        let Some((stats, _)) = self.player_stats(key) else {
+            return;
+        };
+        if stats.exit_time.is_none() && !stats.disconnected {
+            stats.disconnected = true;
+            warn!("Player '{}' disconnected before exiting the maze", key.player);
+        }
+    }
+
+    /// La partie est finie lorsque chaque joueur inscrit est sorti du labyrinthe ou a quitté la partie.
+    fn is_game_over(&self) -> bool {
+        let mut players = self.teams.values().flat_map(|team| team.stats.values()).peekable();
+        players.peek().is_some() && players.all(|stats| stats.exit_time.is_some() || stats.disconnected)
+    }
+
+    /// Classement des équipes : les équipes entièrement sorties d'abord, puis le moins de déplacements,
+    /// puis la sortie la plus rapide.
+    pub fn ranking(&self) -> Vec<TeamResult> {
+        let mut results: Vec<TeamResult> = self
+            .teams
+            .values()
+            .map(|team| {
+                let mut player_results: Vec<(String, PlayerStats)> =
+                    team.stats.iter().map(|(name, stats)| (name.clone(), stats.clone())).collect();
+                player_results.sort_by(|a, b| a.0.cmp(&b.0));
+                let players_exited = player_results.iter().filter(|(_, stats)| stats.exit_time.is_some()).count();
+                let players_disconnected = player_results.iter().filter(|(_, stats)| stats.disconnected).count();
+                let exit_time = if players_exited == player_results.len() {
+                    player_results.iter().filter_map(|(_, stats)| stats.exit_time).max()
+                } else {
+                    None
+                };
+                TeamResult {
+                    team: team.name.clone(),
+                    moves: player_results.iter().map(|(_, stats)| stats.moves).sum(),
+                    challenges_solved: player_results.iter().map(|(_, stats)| stats.challenges_solved).sum(),
+                    players_exited,
+                    players_disconnected,
+                    players: player_results.len(),
+                    exit_time,
+                    player_results,
+                }
+            })
+            .collect();
+        results.sort_by(|a, b| {
+            a.exit_time
+                .is_none()
+                .cmp(&b.exit_time.is_none())
+                .then(b.players_exited.cmp(&a.players_exited))
+                .then(a.moves.cmp(&b.moves))
+                .then(a.exit_time.cmp(&b.exit_time))
+                .then(a.team.cmp(&b.team))
+        });
+        results
+    }
+
+    /// Journalise le classement et l'écrit dans `results-<partie>.json`, dans le répertoire des résultats.
+    fn publish_results(&self) {
+        let ranking = self.ranking();
+        info!("Game '{}' over, ranking:", self.game);
+        for (index, result) in ranking.iter().enumerate() {
+            info!(
+                "#{} {} - {} moves, {} challenges solved, {}/{} players exited, {} disconnected, exit time {:?}",
+                index + 1,
+                result.team,
+                result.moves,
+                result.challenges_solved,
+                result.players_exited,
+                result.players,
+                result.players_disconnected,
+                result.exit_time
+            );
+        }
+
+        let results: Vec<Value> = ranking.iter().enumerate().map(|(index, result)| result.to_json(index + 1)).collect();
+        let content = serde_json::to_string_pretty(&json!({ "ranking": results })).unwrap_or_default();
+        let file = self.results_dir.join(format!("{}-{}.json", RESULTS_FILE_PREFIX, self.game));
+        match fs::create_dir_all(&self.results_dir).and_then(|()| fs::write(&file, content + "\n")) {
+            Ok(()) => info!("Results written to {}", file.display()),
+            Err(e) => error!("Failed to write results to {}: {}", file.display(), e),
+        }
+    }
+}
+
 impl TeamCommand {
-    fn create_process(name_team: String, stream: TcpStream, team_manager: Arc<Mutex<TeamManager>>) {
-        let mut manager = team_manager.lock().unwrap();
-        info!("Processing team creation for '{}'", name_team);
+    fn create_process(name_team: String, stream: TcpStream, game: Arc<Mutex<Game>>) -> Option<String> {
+        let mut game = game.lock().unwrap();
+        info!("Processing team creation for '{}' in game '{}'", name_team, game.name());
+
+        let mut token = None;
 
-        let message = match manager.create_team(&name_team) {
-            Ok(access_key) => register_team_result(Ok((MAX_PLAYER, access_key))),
+        let message = match game.teams.create_team(&name_team) {
+            Ok(access_key) => {
+                game.update_phase();
+                token = Some(access_key.clone());
+                register_team_result(Ok((MAX_PLAYER, access_key)))
+            }
             Err(error) => {
                 error!("Failed to create team '{}': {:?}", name_team, error);
                 register_team_result(Err(error))
@@ -89,21 +453,22 @@ impl TeamCommand {
         if let Err(e) = send_to_client(&stream, message) {
             error!("Failed to send team creation response: {}", e);
         }
+        token
     }
 
-    fn register_player_process(name_player: String, registration_token: String, stream: TcpStream, team_manager: Arc<Mutex<TeamManager>>) {
-        let mut manager = team_manager.lock().unwrap();
+    fn register_player_process(name_player: String, registration_token: String, stream: TcpStream, game: Arc<Mutex<Game>>) -> Option<PlayerKey> {
+        let mut game = game.lock().unwrap();
         info!(
             "Processing player registration: '{}' with token '{}'",
             name_player, registration_token
         );
 
-        let mut message_radar_view = String::from("");
+        let mut key = None;
 
-        let message = match manager.register_player(registration_token.clone(), name_player.clone()) {
+        let message = match game.register_player(registration_token.clone(), name_player.clone()) {
             Ok(player) => {
                 info!("Player '{}' successfully subscribed", name_player);
-                message_radar_view = radar_view(player.clone());
+                key = Some(PlayerKey { game: game.name().to_string(), registration_token, player: name_player });
                 subscribe_player_result (Ok(player))
             }
             Err(error) => {
@@ -116,16 +481,265 @@ impl TeamCommand {
             error!("Failed to send player registration response: {}", e);
         }
 
-        send_to_client(&stream, message_radar_view);
+        if let Some(key) = &key {
+            game.teams.join_waiting_room(key.clone(), stream);
+            game.update_phase();
+        }
+        key
     }
 
-    pub fn process(command: TeamCommand, stream: TcpStream, team_manager: Arc<Mutex<TeamManager>>) {
+    /// Traite une commande d'équipe ; retourne la nouvelle phase de la connexion après une inscription réussie.
+    pub fn process(command: TeamCommand, stream: TcpStream, game: Arc<Mutex<Game>>) -> Option<ConnectionState> {
+        let game_name = game.lock().unwrap().name().to_string();
         match command {
-            TeamCommand::Create(name_team) => Self::create_process(name_team, stream, team_manager),
+            TeamCommand::Create(name_team) => Self::create_process(name_team, stream, game)
+                .map(|registration_token| ConnectionState::Team { game: game_name, registration_token }),
             TeamCommand::SubscribePlayer {
                 name,
                 registration_token,
-            } => Self::register_player_process(name, registration_token, stream, team_manager),
-        };
+            } => Self::register_player_process(name, registration_token, stream, game).map(ConnectionState::Player),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::env;
+    use std::io::{ErrorKind, Read};
+    use std::net::TcpListener;
+    use commun::{decodage::decode_message, structs::JsonWrapper};
+
+    fn spawn() -> Point {
+        Point { x: 1, y: 1 }
+    }
+
+    /// Connexion locale : le côté serveur, puis le côté client.
+    fn connection() -> (TcpStream, TcpStream) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let client = TcpStream::connect(listener.local_addr().unwrap()).unwrap();
+        let (server, _) = listener.accept().unwrap();
+        (server, client)
+    }
+
+    /// Vérifie qu'aucun message n'attend d'être lu sur la connexion.
+    fn assert_no_message(client: &TcpStream) {
+        client.set_nonblocking(true).unwrap();
+        let error = (&*client).read(&mut [0_u8; 1]).unwrap_err();
+        assert_eq!(error.kind(), ErrorKind::WouldBlock);
+        client.set_nonblocking(false).unwrap();
+    }
+
+    fn register(manager: &mut TeamManager, token: &str, name: &str) -> Result<Player, RegistrationError> {
+        manager.register_player(token.to_string(), name.to_string(), spawn)
+    }
+
+    #[test]
+    fn test_create_team_validates_name() {
+        let mut manager = TeamManager::new("test", 2, env::temp_dir());
+        for name in ["", "   ", "team name", "équipe", "a".repeat(MAX_NAME_LENGTH + 1).as_str()] {
+            assert_eq!(manager.create_team(&name.to_string()), Err(RegistrationError::InvalidName), "{:?}", name);
+        }
+        assert!(manager.create_team(&"a".repeat(MAX_NAME_LENGTH)).is_ok());
+        assert!(manager.create_team(&"ci-1/rust_warriors".to_string()).is_ok());
+    }
+
+    #[test]
+    fn test_create_team_already_registered() {
+        let mut manager = TeamManager::new("test", 2, env::temp_dir());
+        assert!(manager.create_team(&"team".to_string()).is_ok());
+        assert_eq!(manager.create_team(&"team".to_string()), Err(RegistrationError::AlreadyRegistered));
+    }
+
+    #[test]
+    fn test_create_team_after_registration_closed() {
+        let mut manager = TeamManager::new("test", 1, env::temp_dir());
+        manager.create_team(&"first".to_string()).unwrap();
+        manager.update_phase(&Maze::generate(3, 3, 0));
+        assert_eq!(manager.phase(), GamePhase::WaitingForPlayers);
+        assert_eq!(manager.create_team(&"second".to_string()), Err(RegistrationError::AlreadyRegistered));
+    }
+
+    #[test]
+    fn test_register_player_invalid_token() {
+        let mut manager = TeamManager::new("test", 1, env::temp_dir());
+        manager.create_team(&"team".to_string()).unwrap();
+        assert_eq!(register(&mut manager, "unknown", "player1").err(), Some(RegistrationError::InvalidRegistrationToken));
+    }
+
+    #[test]
+    fn test_register_player_validates_name() {
+        let mut manager = TeamManager::new("test", 1, env::temp_dir());
+        let token = manager.create_team(&"team".to_string()).unwrap();
+        for name in ["", " ", "player 1", "game/player", "p".repeat(MAX_NAME_LENGTH + 1).as_str()] {
+            assert_eq!(register(&mut manager, &token, name).err(), Some(RegistrationError::InvalidName), "{:?}", name);
+        }
+        assert!(register(&mut manager, &token, "player_1").is_ok());
+    }
+
+    #[test]
+    fn test_register_player_already_registered() {
+        let mut manager = TeamManager::new("test", 1, env::temp_dir());
+        let token = manager.create_team(&"team".to_string()).unwrap();
+        register(&mut manager, &token, "player1").unwrap();
+        assert_eq!(register(&mut manager, &token, "player1").err(), Some(RegistrationError::AlreadyRegistered));
+
+        // Un même nom reste possible dans une autre équipe.
+        let other = manager.create_team(&"other".to_string()).unwrap();
+        assert!(register(&mut manager, &other, "player1").is_ok());
+    }
+
+    #[test]
+    fn test_register_player_too_many_players() {
+        let mut manager = TeamManager::new("test", 2, env::temp_dir());
+        let token = manager.create_team(&"team".to_string()).unwrap();
+        for index in 1..=MAX_PLAYER {
+            register(&mut manager, &token, &format!("player{}", index)).unwrap();
+        }
+        assert_eq!(register(&mut manager, &token, "extra").err(), Some(RegistrationError::TooManyPlayers));
+    }
+
+    #[test]
+    fn test_register_player_after_game_started() {
+        let maze = Maze::generate(3, 3, 0);
+        let mut manager = TeamManager::new("test", 2, env::temp_dir());
+        let first = manager.create_team(&"first".to_string()).unwrap();
+        let second = manager.create_team(&"second".to_string()).unwrap();
+        for index in 1..=MAX_PLAYER {
+            register(&mut manager, &first, &format!("player{}", index)).unwrap();
+        }
+        for index in 1..MAX_PLAYER {
+            register(&mut manager, &second, &format!("player{}", index)).unwrap();
+        }
+        manager.update_phase(&maze);
+        assert_eq!(manager.phase(), GamePhase::WaitingForPlayers);
+
+        register(&mut manager, &second, &format!("player{}", MAX_PLAYER)).unwrap();
+        manager.update_phase(&maze);
+        assert_eq!(manager.phase(), GamePhase::Running);
+        assert_eq!(register(&mut manager, &second, "late").err(), Some(RegistrationError::TooManyPlayers));
+    }
+
+    #[test]
+    fn test_update_phase_transitions() {
+        let maze = Maze::generate(3, 3, 0);
+        let mut manager = TeamManager::new("test", 2, env::temp_dir());
+        manager.update_phase(&maze);
+        assert_eq!(manager.phase(), GamePhase::Registration);
+
+        let first = manager.create_team(&"first".to_string()).unwrap();
+        manager.update_phase(&maze);
+        assert_eq!(manager.phase(), GamePhase::Registration);
+
+        let second = manager.create_team(&"second".to_string()).unwrap();
+        manager.update_phase(&maze);
+        assert_eq!(manager.phase(), GamePhase::WaitingForPlayers);
+
+        for token in [&first, &second] {
+            for index in 1..=MAX_PLAYER {
+                register(&mut manager, token, &format!("player{}", index)).unwrap();
+            }
+        }
+        manager.update_phase(&maze);
+        assert_eq!(manager.phase(), GamePhase::Running);
+    }
+
+    #[test]
+    fn test_waiting_room_receives_first_radar_together() {
+        let maze = Maze::generate(3, 3, 0);
+        let mut manager = TeamManager::new("test", 1, env::temp_dir());
+        let token = manager.create_team(&"team".to_string()).unwrap();
+        manager.update_phase(&maze);
+
+        let mut clients = vec![];
+        for index in 1..=MAX_PLAYER {
+            let name = format!("player{}", index);
+            register(&mut manager, &token, &name).unwrap();
+            let (server, client) = connection();
+            let key = PlayerKey { game: "test".to_string(), registration_token: token.clone(), player: name };
+            manager.join_waiting_room(key, server);
+            manager.update_phase(&maze);
+            clients.push(client);
+
+            if index < MAX_PLAYER {
+                // Tant qu'un joueur manque, personne ne reçoit de vue radar.
+                assert_eq!(manager.phase(), GamePhase::WaitingForPlayers);
+                for client in &clients {
+                    assert_no_message(client);
+                }
+            }
+        }
+
+        assert_eq!(manager.phase(), GamePhase::Running);
+        assert!(manager.waiting_room.is_empty());
+        for mut client in clients {
+            assert!(matches!(decode_message(&mut client), Ok(JsonWrapper::RadarView(_))));
+        }
+    }
+
+    /// Démarre une partie d'une équipe par nom, avec `MAX_PLAYER` joueurs chacune (`player1`, `player2`...).
+    fn running_game(game: &str, teams: &[&str]) -> (TeamManager, Vec<String>) {
+        let mut manager = TeamManager::new(game, teams.len(), env::temp_dir());
+        let tokens: Vec<String> = teams.iter().map(|team| manager.create_team(&team.to_string()).unwrap()).collect();
+        for token in &tokens {
+            for index in 1..=MAX_PLAYER {
+                register(&mut manager, token, &format!("player{}", index)).unwrap();
+            }
+        }
+        manager.update_phase(&Maze::generate(3, 3, 0));
+        assert_eq!(manager.phase(), GamePhase::Running);
+        (manager, tokens)
+    }
+
+    fn key(game: &str, token: &str, player: u8) -> PlayerKey {
+        PlayerKey { game: game.to_string(), registration_token: token.to_string(), player: format!("player{}", player) }
+    }
+
+    #[test]
+    fn test_ranking_order() {
+        let (mut manager, tokens) = running_game("ranking", &["slow", "fast", "partial", "lost"]);
+        let [slow, fast, partial, lost] = [&tokens[0], &tokens[1], &tokens[2], &tokens[3]];
+        for player in 1..=MAX_PLAYER {
+            manager.record_move(&key("ranking", slow, player), false);
+            manager.record_move(&key("ranking", slow, player), true);
+            manager.record_move(&key("ranking", fast, player), true);
+        }
+        manager.record_move(&key("ranking", partial, 1), true);
+        manager.record_move(&key("ranking", lost, 1), false);
+
+        let ranking: Vec<String> = manager.ranking().into_iter().map(|result| result.team).collect();
+        assert_eq!(ranking, ["fast", "slow", "partial", "lost"]);
+    }
+
+    #[test]
+    fn test_moves_after_exit_are_not_counted() {
+        let (mut manager, tokens) = running_game("exit", &["team"]);
+        let player = key("exit", &tokens[0], 1);
+        manager.record_move(&player, false);
+        manager.record_move(&player, true);
+        assert!(manager.has_exited(&player));
+        assert!(!manager.has_exited(&key("exit", &tokens[0], 2)));
+
+        manager.record_move(&player, false);
+        manager.record_move(&player, true);
+        assert_eq!(manager.ranking()[0].moves, 2);
+    }
+
+    #[test]
+    fn test_game_ends_when_remaining_players_exit_or_disconnect() {
+        let game = "disconnection-test";
+        let maze = Maze::generate(3, 3, 0);
+        let (mut manager, tokens) = running_game(game, &["team"]);
+        manager.record_move(&key(game, &tokens[0], 1), true);
+        manager.record_disconnection(&key(game, &tokens[0], 2));
+        manager.update_phase(&maze);
+        assert_eq!(manager.phase(), GamePhase::Running);
+
+        manager.record_disconnection(&key(game, &tokens[0], 3));
+        manager.update_phase(&maze);
+        assert_eq!(manager.phase(), GamePhase::Finished);
+        let result = &manager.ranking()[0];
+        assert_eq!((result.players_exited, result.players_disconnected), (1, 2));
+        fs::remove_file(env::temp_dir().join(format!("{}-{}.json", RESULTS_FILE_PREFIX, game))).unwrap();
     }
 }